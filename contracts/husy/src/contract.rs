@@ -1,12 +1,31 @@
 use std::collections::HashMap;
 
-use near_sdk::{json_types::U128, AccountId, PromiseOrValue};
+use near_sdk::{
+    json_types::{Base58PublicKey, Base64VecU8, U128},
+    AccountId, Promise, PromiseOrValue,
+};
 
 use crate::models::{
+    auction::AuctionView,
+    category::{CategoryId, CategoryView},
+    comment::{CommentId, CommentView},
     husy_metadata::HusyNFTContractMetadata,
+    like_revenue_split::LikeRevenueSplit,
+    likes_config::LikesConfig,
+    listing::ListingView,
     meme::{MemeTokenId, MemeTokenView},
+    meme_like::MemeLikeView,
     meme_metadata::MemeTokenMetadata,
+    metadata_update::{MemeMetadataPatch, MetadataRevision},
+    moderation::{MemeModerationView, ReportView},
+    offer::OfferView,
     payout::Payout,
+    provenance::ProvenanceEntry,
+    role::Role,
+    series::{SeriesId, SeriesView},
+    storage_balance::{StorageBalance, StorageBalanceBounds},
+    trending::TrendingWindow,
+    voucher::Voucher,
 };
 
 pub trait ContractInit {
@@ -17,6 +36,24 @@ pub trait ContractInit {
 
 pub trait NFTContractMetadata {
     fn nft_metadata(&self) -> HusyNFTContractMetadata;
+
+    fn set_contract_name(&mut self, name: String);
+
+    fn set_contract_symbol(&mut self, symbol: String);
+
+    fn set_contract_icon(&mut self, icon: Option<String>);
+
+    fn set_base_uri(&mut self, base_uri: Option<String>);
+
+    fn set_contract_reference(
+        &mut self,
+        reference: Option<String>,
+        reference_hash: Option<Base64VecU8>,
+    );
+
+    fn get_resolve_relative_uris(&self) -> bool;
+
+    fn set_resolve_relative_uris(&mut self, resolve_relative_uris: bool);
 }
 
 pub trait MintNFT {
@@ -26,6 +63,8 @@ pub trait MintNFT {
         token_metadata: MemeTokenMetadata,
         receiver_id: AccountId,
         royalties: Option<HashMap<AccountId, u32>>,
+        category: Option<CategoryId>,
+        remix_of: Option<MemeTokenId>,
     );
 }
 
@@ -58,6 +97,10 @@ pub trait NFTTokenCore {
     ) -> bool;
 }
 
+pub trait NFTBurn {
+    fn nft_burn(&mut self, token_id: MemeTokenId);
+}
+
 pub trait NFTEnumeration {
     fn nft_total_supply(&self) -> U128;
 
@@ -89,16 +132,21 @@ pub trait NFTApproval {
 }
 
 pub trait NFTRoyality {
-    fn nft_payout(&self, token_id: MemeTokenId, balance: U128, max_len_payout: u32) -> Payout;
+    fn nft_payout(
+        &self,
+        token_id: MemeTokenId,
+        balance: U128,
+        max_len_payout: Option<u32>,
+    ) -> Payout;
 
     fn nft_transfer_payout(
         &mut self,
         receiver_id: AccountId,
         token_id: MemeTokenId,
-        approval_id: u64,
+        approval_id: Option<u64>,
         memo: Option<String>,
         balance: U128,
-        max_len_payout: u32,
+        max_len_payout: Option<u32>,
     ) -> Payout;
 }
 
@@ -115,4 +163,308 @@ pub trait MemeInteraction {
         owner: Option<AccountId>,
         main_page_only: bool,
     ) -> Vec<MemeTokenView>;
+
+    fn get_memes_by_creator(
+        &self,
+        creator_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView>;
+
+    fn get_account_like(&self, account_id: AccountId, meme_id: MemeTokenId)
+        -> Option<MemeLikeView>;
+
+    fn get_likes_by_account(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeLikeView>;
+
+    fn get_likers_of_meme(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeLikeView>;
+
+    fn remove_burned_meme_likes(&mut self, meme_id: MemeTokenId, limit: Option<u64>) -> u64;
+}
+
+pub trait LikesRevenue {
+    fn get_like_revenue_split(&self) -> LikeRevenueSplit;
+
+    fn set_like_revenue_split(&mut self, split: LikeRevenueSplit);
+
+    fn get_treasury_balance(&self) -> U128;
+
+    fn get_reward_pool_balance(&self) -> U128;
+
+    fn withdraw_treasury(&mut self, receiver_id: AccountId, amount: U128) -> Promise;
+
+    fn withdraw_reward_pool(&mut self, receiver_id: AccountId, amount: U128) -> Promise;
+}
+
+pub trait LikesConfiguration {
+    fn get_likes_config(&self) -> LikesConfig;
+
+    fn set_yocto_near_per_like(&mut self, yocto_near_per_like: U128);
+
+    fn set_main_average_factor(&mut self, main_average_factor: u32);
+
+    fn set_min_likes(&mut self, min_likes: u64);
+
+    fn set_min_time_to_switch_mode(&mut self, min_time_to_switch_mode: u64);
+
+    fn set_trending_half_life(&mut self, trending_half_life: u64);
+}
+
+pub trait Admin {
+    fn get_owner(&self) -> AccountId;
+
+    fn get_pending_owner(&self) -> Option<AccountId>;
+
+    fn propose_owner(&mut self, new_owner_id: AccountId);
+
+    fn accept_ownership(&mut self);
+
+    fn is_paused(&self) -> bool;
+
+    fn pause(&mut self);
+
+    fn unpause(&mut self);
+
+    fn has_role(&self, account_id: AccountId, role: Role) -> bool;
+
+    fn get_roles(&self, account_id: AccountId) -> Vec<Role>;
+
+    fn grant_role(&mut self, account_id: AccountId, role: Role);
+
+    fn revoke_role(&mut self, account_id: AccountId, role: Role);
+}
+
+pub trait CategoryRegistry {
+    fn add_category(&mut self, category_id: CategoryId, name: String);
+
+    fn rename_category(&mut self, category_id: CategoryId, name: String);
+
+    fn retire_category(&mut self, category_id: CategoryId);
+
+    fn get_category(&self, category_id: CategoryId) -> Option<CategoryView>;
+
+    fn get_categories(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<CategoryView>;
+
+    fn recategorize_meme(&mut self, meme_id: MemeTokenId, category_id: CategoryId);
+}
+
+pub trait TrendingFeed {
+    fn get_trending_memes(
+        &self,
+        window: TrendingWindow,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView>;
+}
+
+pub trait StorageManagement {
+    fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance;
+
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance;
+
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool;
+
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds;
+
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
+}
+
+pub trait Upgradable {
+    fn upgrade(&self);
+
+    fn migrate() -> Self;
+
+    fn migrate_memes(&mut self, limit: Option<u64>) -> u64;
+
+    fn is_migration_finished(&self) -> bool;
+}
+
+pub trait Marketplace {
+    fn list_meme(&mut self, token_id: MemeTokenId, price: U128);
+
+    fn update_price(&mut self, token_id: MemeTokenId, price: U128);
+
+    fn delist(&mut self, token_id: MemeTokenId);
+
+    fn buy_meme(&mut self, token_id: MemeTokenId);
+
+    fn get_listing(&self, token_id: MemeTokenId) -> Option<ListingView>;
+
+    fn get_listings(
+        &self,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+        category: Option<CategoryId>,
+        owner: Option<AccountId>,
+    ) -> Vec<ListingView>;
+}
+
+pub trait Auctions {
+    fn create_auction(
+        &mut self,
+        token_id: MemeTokenId,
+        reserve_price: U128,
+        ends_at: u64,
+        min_increment: U128,
+    );
+
+    fn place_bid(&mut self, token_id: MemeTokenId);
+
+    fn cancel_auction(&mut self, token_id: MemeTokenId);
+
+    fn settle_auction(&mut self, token_id: MemeTokenId);
+
+    fn get_auction(&self, token_id: MemeTokenId) -> Option<AuctionView>;
+
+    fn get_auctions(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<AuctionView>;
+}
+
+pub trait Offers {
+    fn make_offer(&mut self, token_id: MemeTokenId, expires_at: u64);
+
+    fn cancel_offer(&mut self, token_id: MemeTokenId);
+
+    fn accept_offer(&mut self, token_id: MemeTokenId, bidder_id: AccountId);
+
+    fn get_offers_for_meme(
+        &self,
+        token_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<OfferView>;
+
+    fn get_offers_by_bidder(
+        &self,
+        bidder_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<OfferView>;
+}
+
+pub trait Moderation {
+    fn report_meme(&mut self, token_id: MemeTokenId, reason: String);
+
+    fn dismiss_reports(&mut self, token_id: MemeTokenId, reason: Option<String>);
+
+    fn hide_meme(&mut self, token_id: MemeTokenId, reason: Option<String>);
+
+    fn unhide_meme(&mut self, token_id: MemeTokenId, reason: Option<String>);
+
+    fn freeze_meme(&mut self, token_id: MemeTokenId, reason: Option<String>);
+
+    fn unfreeze_meme(&mut self, token_id: MemeTokenId, reason: Option<String>);
+
+    fn get_reports(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<ReportView>;
+
+    fn get_meme_moderation(&self, token_id: MemeTokenId) -> Option<MemeModerationView>;
+
+    fn get_hidden_memes(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<MemeTokenView>;
+}
+
+pub trait Comments {
+    fn add_comment(
+        &mut self,
+        meme_id: MemeTokenId,
+        text: String,
+        parent_id: Option<CommentId>,
+    ) -> CommentId;
+
+    fn delete_comment(&mut self, meme_id: MemeTokenId, comment_id: CommentId);
+
+    fn get_comments(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<CommentView>;
+
+    fn remove_burned_meme_comments(&mut self, meme_id: MemeTokenId, limit: Option<u64>) -> u64;
+}
+
+pub trait Remixes {
+    fn get_remix_royalty(&self) -> u32;
+
+    fn set_remix_royalty(&mut self, remix_royalty: u32);
+
+    fn get_remixes(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView>;
+
+    fn get_lineage(&self, meme_id: MemeTokenId, limit: Option<u64>) -> Vec<MemeTokenId>;
+}
+
+pub trait Editions {
+    fn create_series(
+        &mut self,
+        metadata: MemeTokenMetadata,
+        max_copies: u64,
+        price: Option<U128>,
+        royalties: Option<HashMap<AccountId, u32>>,
+        category: Option<CategoryId>,
+    ) -> SeriesId;
+
+    fn set_series_price(&mut self, series_id: SeriesId, price: Option<U128>);
+
+    fn mint_edition(&mut self, series_id: SeriesId, receiver_id: AccountId) -> MemeTokenId;
+
+    fn buy_edition(&mut self, series_id: SeriesId) -> MemeTokenId;
+
+    fn get_series(&self, series_id: SeriesId) -> Option<SeriesView>;
+
+    fn get_series_list(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<SeriesView>;
+
+    fn get_series_editions(
+        &self,
+        series_id: SeriesId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView>;
+}
+
+pub trait LazyMinting {
+    fn register_creator_key(&mut self, public_key: Base58PublicKey);
+
+    fn remove_creator_key(&mut self);
+
+    fn get_creator_key(&self, account_id: AccountId) -> Option<Base58PublicKey>;
+
+    fn redeem_voucher(
+        &mut self,
+        voucher: Voucher,
+        metadata: MemeTokenMetadata,
+        signature: Base64VecU8,
+    ) -> MemeTokenId;
+
+    fn is_voucher_redeemed(&self, voucher: Voucher) -> bool;
+}
+
+pub trait NFTMetadataUpdate {
+    fn nft_update_metadata(&mut self, token_id: MemeTokenId, patch: MemeMetadataPatch);
+
+    fn get_metadata_revisions(&self, token_id: MemeTokenId) -> Vec<MetadataRevision>;
+}
+
+pub trait Provenance {
+    fn get_provenance(
+        &self,
+        token_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<ProvenanceEntry>;
 }
@@ -0,0 +1,421 @@
+use near_sdk::{
+    env,
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    serde_json, AccountId,
+};
+
+use crate::models::meme::MemeTokenId;
+
+const NFT_STANDARD_NAME: &str = "nep171";
+const NFT_STANDARD_VERSION: &str = "1.0.0";
+const HUSY_STANDARD_NAME: &str = "husy";
+const HUSY_EVENTS_VERSION: &str = "1.0.0";
+
+/// Event log in NEP-297 format
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EventLog<T> {
+    pub standard: String,
+    pub version: String,
+    #[serde(flatten)]
+    pub event: T,
+}
+
+impl<T: Serialize> EventLog<T> {
+    fn emit(&self) {
+        env::log(format!("EVENT_JSON:{}", serde_json::to_string(self).unwrap()).as_bytes());
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+pub enum NftEvent {
+    #[serde(rename = "nft_mint")]
+    Mint(Vec<NftMintLog>),
+    #[serde(rename = "nft_transfer")]
+    Transfer(Vec<NftTransferLog>),
+    #[serde(rename = "nft_burn")]
+    Burn(Vec<NftBurnLog>),
+}
+
+impl NftEvent {
+    pub fn emit(self) {
+        EventLog {
+            standard: NFT_STANDARD_NAME.to_owned(),
+            version: NFT_STANDARD_VERSION.to_owned(),
+            event: self,
+        }
+        .emit()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftMintLog {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<MemeTokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftTransferLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub token_ids: Vec<MemeTokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftBurnLog {
+    pub owner_id: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    pub token_ids: Vec<MemeTokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum HusyEvent {
+    MemeLiked(Vec<MemeLikedLog>),
+    MemePromotedToMain(Vec<MemePromotedToMainLog>),
+    LikesGroupSwapped(Vec<LikesGroupSwappedLog>),
+    ApprovalGranted(Vec<ApprovalGrantedLog>),
+    ApprovalRevoked(Vec<ApprovalRevokedLog>),
+    MemeListed(Vec<MemeListingLog>),
+    ListingPriceUpdated(Vec<MemeListingLog>),
+    MemeDelisted(Vec<MemeDelistedLog>),
+    MemeSold(Vec<MemeSoldLog>),
+    AuctionCreated(Vec<AuctionCreatedLog>),
+    AuctionBid(Vec<AuctionBidLog>),
+    AuctionCancelled(Vec<AuctionCancelledLog>),
+    AuctionSettled(Vec<AuctionSettledLog>),
+    OfferMade(Vec<OfferMadeLog>),
+    OfferCancelled(Vec<OfferRemovedLog>),
+    OfferAccepted(Vec<OfferAcceptedLog>),
+    MemeReported(Vec<MemeReportedLog>),
+    ReportsDismissed(Vec<ModerationDecisionLog>),
+    MemeHidden(Vec<ModerationDecisionLog>),
+    MemeUnhidden(Vec<ModerationDecisionLog>),
+    MemeFrozen(Vec<ModerationDecisionLog>),
+    MemeUnfrozen(Vec<ModerationDecisionLog>),
+    CommentAdded(Vec<CommentAddedLog>),
+    CommentDeleted(Vec<CommentDeletedLog>),
+    MemeRemixed(Vec<MemeRemixedLog>),
+    SeriesCreated(Vec<SeriesCreatedLog>),
+    SeriesPriceUpdated(Vec<SeriesPriceUpdatedLog>),
+    EditionSold(Vec<EditionSoldLog>),
+    VoucherRedeemed(Vec<VoucherRedeemedLog>),
+    MemeMetadataUpdated(Vec<MemeMetadataUpdatedLog>),
+}
+
+impl HusyEvent {
+    pub fn emit(self) {
+        EventLog {
+            standard: HUSY_STANDARD_NAME.to_owned(),
+            version: HUSY_EVENTS_VERSION.to_owned(),
+            event: self,
+        }
+        .emit()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeLikedLog {
+    pub meme_id: MemeTokenId,
+    pub account_id: AccountId,
+    pub likes: u64,
+    pub total_likes: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemePromotedToMainLog {
+    pub meme_id: MemeTokenId,
+    pub likes: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LikesGroupSwappedLog {
+    pub swapped_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApprovalGrantedLog {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+    pub account_id: AccountId,
+    pub approval_id: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApprovalRevokedLog {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+    pub account_ids: Vec<AccountId>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeListingLog {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+    pub price: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeDelistedLog {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeSoldLog {
+    pub token_id: MemeTokenId,
+    pub seller_id: AccountId,
+    pub buyer_id: AccountId,
+    pub price: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuctionCreatedLog {
+    pub token_id: MemeTokenId,
+    pub seller_id: AccountId,
+    pub reserve_price: U128,
+    pub min_increment: U128,
+    pub ends_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuctionBidLog {
+    pub token_id: MemeTokenId,
+    pub bidder_id: AccountId,
+    pub amount: U128,
+    pub ends_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuctionCancelledLog {
+    pub token_id: MemeTokenId,
+    pub seller_id: AccountId,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuctionSettledLog {
+    pub token_id: MemeTokenId,
+    pub seller_id: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub winner_id: Option<AccountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OfferMadeLog {
+    pub token_id: MemeTokenId,
+    pub bidder_id: AccountId,
+    pub amount: U128,
+    pub expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OfferRemovedLog {
+    pub token_id: MemeTokenId,
+    pub bidder_id: AccountId,
+    pub amount: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OfferAcceptedLog {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+    pub bidder_id: AccountId,
+    pub amount: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeReportedLog {
+    pub report_id: u64,
+    pub token_id: MemeTokenId,
+    pub reporter_id: AccountId,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ModerationDecisionLog {
+    pub token_id: MemeTokenId,
+    pub moderator_id: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CommentAddedLog {
+    pub comment_id: u64,
+    pub meme_id: MemeTokenId,
+    pub author_id: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CommentDeletedLog {
+    pub comment_id: u64,
+    pub meme_id: MemeTokenId,
+    pub author_id: AccountId,
+    pub deleted_by: AccountId,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeRemixedLog {
+    pub token_id: MemeTokenId,
+    pub remix_of: MemeTokenId,
+    pub parent_creator_id: AccountId,
+    pub royalty: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeriesCreatedLog {
+    pub series_id: u64,
+    pub creator_id: AccountId,
+    pub max_copies: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeriesPriceUpdatedLog {
+    pub series_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EditionSoldLog {
+    pub series_id: u64,
+    pub token_id: MemeTokenId,
+    pub creator_id: AccountId,
+    pub buyer_id: AccountId,
+    pub price: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VoucherRedeemedLog {
+    pub token_id: MemeTokenId,
+    pub creator_id: AccountId,
+    pub buyer_id: AccountId,
+    pub price: U128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeMetadataUpdatedLog {
+    pub token_id: MemeTokenId,
+    pub creator_id: AccountId,
+    pub media_changed: bool,
+    pub updated_at: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env,
+    };
+
+    use super::*;
+
+    #[test]
+    fn nft_mint_log_format() {
+        testing_env!(VMContextBuilder::new().build());
+
+        NftEvent::Mint(vec![NftMintLog {
+            owner_id: "foundation.near".to_owned(),
+            token_ids: vec!["aurora".to_owned(), "proximitylabs".to_owned()],
+            memo: None,
+        }])
+        .emit();
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[{"owner_id":"foundation.near","token_ids":["aurora","proximitylabs"]}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn nft_transfer_log_format() {
+        testing_env!(VMContextBuilder::new().build());
+
+        NftEvent::Transfer(vec![NftTransferLog {
+            authorized_id: Some("market.near".to_owned()),
+            old_owner_id: "user1.near".to_owned(),
+            new_owner_id: "user2.near".to_owned(),
+            token_ids: vec!["meme".to_owned()],
+            memo: Some("have fun".to_owned()),
+        }])
+        .emit();
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"authorized_id":"market.near","old_owner_id":"user1.near","new_owner_id":"user2.near","token_ids":["meme"],"memo":"have fun"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn husy_event_log_format() {
+        testing_env!(VMContextBuilder::new().build());
+
+        HusyEvent::MemeLiked(vec![MemeLikedLog {
+            meme_id: "meme".to_owned(),
+            account_id: "user.near".to_owned(),
+            likes: 2,
+            total_likes: 5,
+        }])
+        .emit();
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_liked","data":[{"meme_id":"meme","account_id":"user.near","likes":2,"total_likes":5}]}"#
+            ]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use near_sdk::{assert_one_yocto, env, near_bindgen, AccountId};
+
+use crate::{
+    contract::Admin,
+    models::{husy::*, role::Role},
+};
+
+#[near_bindgen]
+impl Admin for HusyContract {
+    fn get_owner(&self) -> AccountId {
+        self.owner_id.clone()
+    }
+
+    fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner_id.clone()
+    }
+
+    #[payable]
+    fn propose_owner(&mut self, new_owner_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert_ne!(
+            new_owner_id, self.owner_id,
+            "Account is already contract owner"
+        );
+
+        self.pending_owner_id = Some(new_owner_id);
+    }
+
+    #[payable]
+    fn accept_ownership(&mut self) {
+        assert_one_yocto();
+        let predecessor_account_id = env::predecessor_account_id();
+        assert_eq!(
+            self.pending_owner_id.as_ref(),
+            Some(&predecessor_account_id),
+            "Only proposed owner can accept ownership"
+        );
+
+        self.owner_id = predecessor_account_id;
+        self.pending_owner_id = None;
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[payable]
+    fn pause(&mut self) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(!self.paused, "Contract is already paused");
+
+        self.paused = true;
+    }
+
+    #[payable]
+    fn unpause(&mut self) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(self.paused, "Contract is not paused");
+        assert!(
+            self.legacy_meme_additional_data.is_none(),
+            "Memes are not migrated yet"
+        );
+
+        self.paused = false;
+    }
+
+    fn has_role(&self, account_id: AccountId, role: Role) -> bool {
+        self.account_has_role(&account_id, &role)
+    }
+
+    fn get_roles(&self, account_id: AccountId) -> Vec<Role> {
+        self.roles_per_account.get(&account_id).unwrap_or_default()
+    }
+
+    #[payable]
+    fn grant_role(&mut self, account_id: AccountId, role: Role) {
+        assert_one_yocto();
+        self.assert_owner();
+
+        let mut roles = self.roles_per_account.get(&account_id).unwrap_or_default();
+        assert!(!roles.contains(&role), "Account already has this role");
+        roles.push(role);
+        self.roles_per_account.insert(&account_id, &roles);
+    }
+
+    #[payable]
+    fn revoke_role(&mut self, account_id: AccountId, role: Role) {
+        assert_one_yocto();
+        self.assert_owner();
+
+        let mut roles = self.roles_per_account.get(&account_id).unwrap_or_default();
+        assert!(roles.contains(&role), "Account does not have this role");
+        roles.retain(|granted_role| granted_role != &role);
+        if roles.is_empty() {
+            self.roles_per_account.remove(&account_id);
+        } else {
+            self.roles_per_account.insert(&account_id, &roles);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::ContractInit;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str, attached: u128) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    #[test]
+    fn two_step_ownership_transfer() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.propose_owner("new_owner.testnet".to_owned());
+        assert_eq!(contract.get_owner(), "owner.testnet".to_owned());
+        assert_eq!(
+            contract.get_pending_owner(),
+            Some("new_owner.testnet".to_owned())
+        );
+
+        testing_env!(get_context("new_owner.testnet", 1));
+        contract.accept_ownership();
+
+        assert_eq!(contract.get_owner(), "new_owner.testnet".to_owned());
+        assert_eq!(contract.get_pending_owner(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn propose_owner_not_owner() {
+        testing_env!(get_context("someone.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.propose_owner("someone.testnet".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only proposed owner can accept ownership")]
+    fn accept_ownership_not_proposed() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.propose_owner("new_owner.testnet".to_owned());
+
+        testing_env!(get_context("someone.testnet", 1));
+        contract.accept_ownership();
+    }
+
+    #[test]
+    fn pause_and_unpause() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.pause();
+        assert!(contract.is_paused());
+
+        contract.unpause();
+        assert!(!contract.is_paused());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn pause_not_owner() {
+        testing_env!(get_context("someone.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.pause();
+    }
+
+    #[test]
+    fn grant_and_revoke_roles() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let account_id = "moderator.testnet".to_owned();
+
+        contract.grant_role(account_id.clone(), Role::Moderator);
+        contract.grant_role(account_id.clone(), Role::Minter);
+        assert!(contract.has_role(account_id.clone(), Role::Moderator));
+        assert!(!contract.has_role(account_id.clone(), Role::Treasurer));
+        assert_eq!(
+            contract.get_roles(account_id.clone()),
+            vec![Role::Moderator, Role::Minter]
+        );
+
+        contract.revoke_role(account_id.clone(), Role::Moderator);
+        contract.revoke_role(account_id.clone(), Role::Minter);
+        assert!(contract.get_roles(account_id.clone()).is_empty());
+        assert!(contract.roles_per_account.get(&account_id).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Account already has this role")]
+    fn grant_role_twice() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.grant_role("treasurer.testnet".to_owned(), Role::Treasurer);
+        contract.grant_role("treasurer.testnet".to_owned(), Role::Treasurer);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn grant_role_not_owner() {
+        testing_env!(get_context("someone.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.grant_role("someone.testnet".to_owned(), Role::Treasurer);
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn pause_without_yocto() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.pause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn grant_role_without_yocto() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.grant_role("minter.testnet".to_owned(), Role::Minter);
+    }
+}
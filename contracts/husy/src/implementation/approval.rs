@@ -2,9 +2,10 @@ use near_sdk::{env, near_bindgen, AccountId, Balance, Gas};
 
 use crate::{
     contract::NFTApproval,
+    events::{ApprovalGrantedLog, ApprovalRevokedLog, HusyEvent},
     ext_contracts::ext_nft_approval_receiver,
     models::{husy::*, meme::MemeTokenId},
-    utils::{asserts::assert_full_access_key, payment::with_refund},
+    utils::asserts::assert_full_access_key,
 };
 
 const GAS_FOR_NFT_APPROVE: Gas = 10_000_000_000_000;
@@ -15,6 +16,7 @@ impl NFTApproval for HusyContract {
     #[payable]
     fn nft_approve(&mut self, token_id: MemeTokenId, account_id: AccountId, msg: Option<String>) {
         assert_full_access_key();
+        self.assert_not_paused();
 
         let mut token = self.memes_by_id.get(&token_id).expect("");
 
@@ -25,17 +27,21 @@ impl NFTApproval for HusyContract {
         );
 
         let approval_id: u64 = token.next_approval_id;
-        let approved_account_id = account_id.clone();
-        with_refund(|| {
-            token
-                .approved_account_ids
-                .insert(approved_account_id, approval_id);
-
-            token.next_approval_id += 1;
-            self.memes_by_id.insert(&token_id, &token);
-
-            ((), None)
-        });
+        let initial_storage_usage = env::storage_usage();
+        token
+            .approved_account_ids
+            .insert(account_id.clone(), approval_id);
+        token.next_approval_id += 1;
+        self.memes_by_id.insert(&token_id, &token);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::ApprovalGranted(vec![ApprovalGrantedLog {
+            token_id: token_id.clone(),
+            owner_id: token.owner_id.clone(),
+            account_id: account_id.clone(),
+            approval_id,
+        }])
+        .emit();
 
         if let Some(msg) = msg {
             ext_nft_approval_receiver::nft_on_approve(
@@ -77,12 +83,17 @@ impl NFTApproval for HusyContract {
             "Unauthorized"
         );
 
-        with_refund(|| {
-            token.approved_account_ids.remove(&account_id);
-            self.memes_by_id.insert(&token_id, &token);
-
-            ((), None)
-        });
+        let initial_storage_usage = env::storage_usage();
+        token.approved_account_ids.remove(&account_id);
+        self.memes_by_id.insert(&token_id, &token);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::ApprovalRevoked(vec![ApprovalRevokedLog {
+            token_id,
+            owner_id: token.owner_id,
+            account_ids: vec![account_id],
+        }])
+        .emit();
     }
 
     fn nft_revoke_all(&mut self, token_id: MemeTokenId) {
@@ -94,12 +105,18 @@ impl NFTApproval for HusyContract {
             "Unauthorized"
         );
 
-        with_refund(|| {
-            token.approved_account_ids.clear();
-            self.memes_by_id.insert(&token_id, &token);
-
-            ((), None)
-        })
+        let account_ids: Vec<AccountId> = token.approved_account_ids.keys().cloned().collect();
+        let initial_storage_usage = env::storage_usage();
+        token.approved_account_ids.clear();
+        self.memes_by_id.insert(&token_id, &token);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::ApprovalRevoked(vec![ApprovalRevokedLog {
+            token_id,
+            owner_id: token.owner_id,
+            account_ids,
+        }])
+        .emit();
     }
 }
 
@@ -112,7 +129,10 @@ mod test {
 
     use super::*;
     use near_sdk::MockedBlockchain;
-    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, VMContext,
+    };
 
     fn get_context(predecessor_account_id: &str, attached_deposit: u128) -> VMContext {
         VMContextBuilder::new()
@@ -203,6 +223,12 @@ mod test {
 
         contract.nft_revoke(token_id.clone(), approved_account_id);
 
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"approval_revoked","data":[{"token_id":"asdfzvczx.testnet","owner_id":"acbvbcvbc.testnet","account_ids":["approved.testnet"]}]}"#
+            ]
+        );
         assert!(contract
             .memes_by_id
             .get(&token_id)
@@ -315,6 +341,17 @@ mod test {
         contract.nft_approve("something".to_owned(), "something".to_owned(), None);
     }
 
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn nft_approve_panics_when_paused() {
+        let ctx = get_context("test.testnet", 1);
+        testing_env!(ctx);
+        let mut contract = HusyContract::new_default("test.testnet".to_owned());
+        contract.paused = true;
+
+        contract.nft_approve("something".to_owned(), "something".to_owned(), None);
+    }
+
     #[test]
     #[should_panic]
     fn nft_approve_panics_user_not_owner() {
@@ -358,5 +395,11 @@ mod test {
         expected_map.insert(approved_account_id, 0);
         assert_eq!(meme.approved_account_ids, expected_map);
         assert_eq!(meme.next_approval_id, 1);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"approval_granted","data":[{"token_id":"qweqw.testnet","owner_id":"terstes.testnet","account_id":"something","approval_id":0}]}"#
+            ]
+        );
     }
 }
@@ -0,0 +1,629 @@
+use near_sdk::{assert_one_yocto, env, json_types::U128, near_bindgen, Promise};
+
+use crate::{
+    contract::Auctions,
+    events::{AuctionBidLog, AuctionCancelledLog, AuctionCreatedLog, AuctionSettledLog, HusyEvent},
+    models::{
+        auction::{Auction, AuctionView, Bid, ANTI_SNIPING_EXTENSION, ANTI_SNIPING_WINDOW},
+        husy::*,
+        meme::MemeTokenId,
+    },
+    utils::payment::{pay_out, refund_approved_account_ids},
+};
+
+#[near_bindgen]
+impl Auctions for HusyContract {
+    #[payable]
+    fn create_auction(
+        &mut self,
+        token_id: MemeTokenId,
+        reserve_price: U128,
+        ends_at: u64,
+        min_increment: U128,
+    ) {
+        self.assert_not_paused();
+        let token = self.assert_meme_owner(&token_id);
+        self.assert_not_on_auction(&token_id);
+        assert!(
+            self.listings.get(&token_id).is_none(),
+            "Meme is listed, delist it first"
+        );
+        assert!(reserve_price.0 > 0, "Reserve price must be greater than 0");
+        assert!(
+            min_increment.0 > 0,
+            "Minimum increment must be greater than 0"
+        );
+        let now = env::block_timestamp();
+        assert!(ends_at > now, "Auction must end in the future");
+
+        let initial_storage_usage = env::storage_usage();
+        self.auctions.insert(
+            &token_id,
+            &Auction {
+                seller_id: token.owner_id.clone(),
+                reserve_price: reserve_price.0,
+                min_increment: min_increment.0,
+                started_at: now,
+                ends_at,
+                highest_bid: None,
+            },
+        );
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::AuctionCreated(vec![AuctionCreatedLog {
+            token_id,
+            seller_id: token.owner_id,
+            reserve_price,
+            min_increment,
+            ends_at,
+        }])
+        .emit();
+    }
+
+    /// Attached deposit is the bid, previous highest bid is refunded. Storage
+    /// of the bid is paid from storage balance of bidder or from attached
+    /// deposit, which lowers bid amount
+    #[payable]
+    fn place_bid(&mut self, token_id: MemeTokenId) {
+        self.assert_not_paused();
+        let mut auction = self
+            .auctions
+            .get(&token_id)
+            .expect("Meme is not on auction");
+        let now = env::block_timestamp();
+        assert!(now < auction.ends_at, "Auction has ended");
+
+        self.assert_auction_seller_owns(&token_id, &auction);
+        let bidder_id = env::predecessor_account_id();
+        assert_ne!(bidder_id, auction.seller_id, "Cannot bid on own auction");
+        let attached = env::attached_deposit();
+        let min_next_bid = auction.min_next_bid();
+        assert!(
+            attached >= min_next_bid,
+            "Bid must be at least {} yoctoNEAR",
+            min_next_bid
+        );
+
+        if let Some(previous_bid) = self.take_highest_bid(&token_id, &mut auction) {
+            Promise::new(previous_bid.bidder_id).transfer(previous_bid.amount);
+        }
+        if auction.ends_at - now < ANTI_SNIPING_WINDOW {
+            auction.ends_at = now + ANTI_SNIPING_EXTENSION;
+        }
+        let initial_storage_usage = env::storage_usage();
+        let mut bid = Bid {
+            bidder_id: bidder_id.clone(),
+            amount: attached,
+            placed_at: now,
+        };
+        auction.highest_bid = Some(bid.clone());
+        self.auctions.insert(&token_id, &auction);
+        bid.amount = self.pay_for_storage(&bidder_id, initial_storage_usage, attached);
+        assert!(
+            bid.amount >= min_next_bid,
+            "Bid must be at least {} yoctoNEAR",
+            min_next_bid
+        );
+        let amount = bid.amount;
+        auction.highest_bid = Some(bid);
+        self.auctions.insert(&token_id, &auction);
+
+        HusyEvent::AuctionBid(vec![AuctionBidLog {
+            token_id,
+            bidder_id,
+            amount: U128(amount),
+            ends_at: auction.ends_at,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn cancel_auction(&mut self, token_id: MemeTokenId) {
+        assert_one_yocto();
+        let auction = self
+            .auctions
+            .get(&token_id)
+            .expect("Meme is not on auction");
+        assert_eq!(
+            auction.seller_id,
+            env::predecessor_account_id(),
+            "Only seller can cancel auction"
+        );
+        assert!(
+            auction.highest_bid.is_none(),
+            "Cannot cancel auction which already has bids"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+        self.auctions.remove(&token_id);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::AuctionCancelled(vec![AuctionCancelledLog {
+            token_id,
+            seller_id: auction.seller_id,
+        }])
+        .emit();
+    }
+
+    /// Can be called by anyone once auction has ended. Meme without bids
+    /// stays with seller. When meme is frozen or seller no longer owns it,
+    /// auction is closed and highest bid refunded, so it is not locked
+    fn settle_auction(&mut self, token_id: MemeTokenId) {
+        self.assert_not_paused();
+        let mut auction = self
+            .auctions
+            .get(&token_id)
+            .expect("Meme is not on auction");
+        assert!(
+            env::block_timestamp() >= auction.ends_at,
+            "Auction has not ended yet"
+        );
+        let seller_owns = self
+            .memes_by_id
+            .get(&token_id)
+            .is_some_and(|token| token.owner_id == auction.seller_id);
+        if !seller_owns || self.frozen_memes.contains(&token_id) {
+            self.close_auction(&token_id);
+            return;
+        }
+
+        let highest_bid = self.take_highest_bid(&token_id, &mut auction);
+        let initial_storage_usage = env::storage_usage();
+        self.auctions.remove(&token_id);
+        let refund = self.pay_for_storage(&auction.seller_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(auction.seller_id.clone()).transfer(refund);
+        }
+
+        if let Some(bid) = &highest_bid {
+            let token = self.memes_by_id.get(&token_id).unwrap();
+            let payout = self.get_meme_payout(token_id.clone(), U128(bid.amount), u32::MAX);
+            let previous = self.internal_meme_transfer(
+                token_id.clone(),
+                token,
+                bid.bidder_id.clone(),
+                None,
+                None,
+            );
+            refund_approved_account_ids(previous.owner_id, &previous.approved_account_ids);
+            pay_out(&payout);
+            self.mark_meme_sold(&token_id, bid.amount);
+        }
+
+        HusyEvent::AuctionSettled(vec![AuctionSettledLog {
+            token_id,
+            seller_id: auction.seller_id,
+            winner_id: highest_bid.as_ref().map(|bid| bid.bidder_id.clone()),
+            price: highest_bid.map(|bid| U128(bid.amount)),
+        }])
+        .emit();
+    }
+
+    fn get_auction(&self, token_id: MemeTokenId) -> Option<AuctionView> {
+        self.get_auction_view(token_id)
+    }
+
+    fn get_auctions(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<AuctionView> {
+        self.auctions
+            .keys()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(self.auctions.len()) as usize)
+            .filter_map(|token_id| self.get_auction_view(token_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, PromiseResult, VMContext,
+    };
+
+    use crate::contract::{ContractInit, Marketplace, NFTBurn, NFTTokenCore, StorageManagement};
+    use crate::models::meme::MemeToken;
+
+    use super::*;
+
+    const START: u64 = 1_000_000_000_000;
+    const END: u64 = START + 3_600_000_000_000;
+    const AUCTION_DEPOSIT: Balance = 10_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance, now: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(now)
+            .build()
+    }
+
+    fn auction_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0, START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let meme_id = "meme".to_owned();
+        contract.memes_by_id.insert(
+            &meme_id,
+            &MemeToken {
+                owner_id: "alice.testnet".to_owned(),
+                royalty: HashMap::from([("artist.testnet".to_owned(), 1_000)]),
+                ..Default::default()
+            },
+        );
+        contract
+            .meme_metadata_by_id
+            .insert(&meme_id, &Default::default());
+        contract
+            .meme_additional_data_by_id
+            .insert(&meme_id, &Default::default());
+        contract.add_meme_to_owner(&"alice.testnet".to_owned(), &meme_id);
+
+        testing_env!(get_context("alice.testnet", AUCTION_DEPOSIT, START));
+        contract.create_auction(meme_id, U128(100), END, U128(10));
+
+        contract
+    }
+
+    fn bid(contract: &mut HusyContract, bidder_id: &str, amount: Balance, now: u64) {
+        if contract.storage_balance_of(bidder_id.to_owned()).is_none() {
+            testing_env!(get_context(bidder_id, AUCTION_DEPOSIT, now));
+            contract.storage_deposit(None, None);
+        }
+        testing_env!(get_context(bidder_id, amount, now));
+        contract.place_bid("meme".to_owned());
+    }
+
+    #[test]
+    fn create_auction_success() {
+        let contract = auction_contract();
+
+        assert_eq!(
+            contract.get_auction("meme".to_owned()),
+            Some(AuctionView {
+                token_id: "meme".to_owned(),
+                seller_id: "alice.testnet".to_owned(),
+                reserve_price: U128(100),
+                min_increment: U128(10),
+                started_at: START,
+                ends_at: END,
+                highest_bidder_id: None,
+                highest_bid: None,
+                min_next_bid: U128(100),
+            })
+        );
+        assert_eq!(contract.get_auctions(None, None).len(), 1);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"auction_created","data":[{"token_id":"meme","seller_id":"alice.testnet","reserve_price":"100","min_increment":"10","ends_at":4600000000000}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Auction must end in the future")]
+    fn create_auction_ending_in_past() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("alice.testnet", 1, START));
+        contract.cancel_auction("meme".to_owned());
+
+        testing_env!(get_context("alice.testnet", AUCTION_DEPOSIT, START));
+        contract.create_auction("meme".to_owned(), U128(100), START, U128(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Predecessor must be meme owner")]
+    fn create_auction_not_owner() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("bob.testnet", AUCTION_DEPOSIT, START));
+
+        contract.create_auction("meme".to_owned(), U128(100), END, U128(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is on auction")]
+    fn list_meme_on_auction() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("alice.testnet", AUCTION_DEPOSIT, START));
+
+        contract.list_meme("meme".to_owned(), U128(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is on auction")]
+    fn transfer_meme_on_auction() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("alice.testnet", 1, START));
+
+        contract.nft_transfer("bob.testnet".to_owned(), "meme".to_owned(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is on auction")]
+    fn burn_meme_on_auction() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("alice.testnet", 1, START));
+
+        contract.nft_burn("meme".to_owned());
+    }
+
+    #[test]
+    fn place_bid_outbids_previous_bidder() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        bid(&mut contract, "carol.testnet", 110, START + 2);
+
+        let auction = contract.get_auction("meme".to_owned()).unwrap();
+        assert_eq!(auction.highest_bidder_id, Some("carol.testnet".to_owned()));
+        assert_eq!(auction.highest_bid, Some(U128(110)));
+        assert_eq!(auction.min_next_bid, U128(120));
+        assert_eq!(auction.ends_at, END);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bid must be at least 100 yoctoNEAR")]
+    fn place_bid_below_reserve_price() {
+        let mut contract = auction_contract();
+
+        bid(&mut contract, "bob.testnet", 99, START + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bid must be at least 110 yoctoNEAR")]
+    fn place_bid_below_min_increment() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+
+        bid(&mut contract, "carol.testnet", 109, START + 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot bid on own auction")]
+    fn place_bid_by_seller() {
+        let mut contract = auction_contract();
+
+        bid(&mut contract, "alice.testnet", 100, START + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Auction has ended")]
+    fn place_bid_after_end() {
+        let mut contract = auction_contract();
+
+        bid(&mut contract, "bob.testnet", 100, END);
+    }
+
+    #[test]
+    fn place_bid_extends_auction_near_end() {
+        let mut contract = auction_contract();
+        let late = END - ANTI_SNIPING_WINDOW / 2;
+
+        bid(&mut contract, "bob.testnet", 100, late);
+
+        assert_eq!(
+            contract.get_auction("meme".to_owned()).unwrap().ends_at,
+            late + ANTI_SNIPING_EXTENSION
+        );
+        bid(&mut contract, "carol.testnet", 110, END + 1);
+        assert_eq!(
+            contract
+                .get_auction("meme".to_owned())
+                .unwrap()
+                .highest_bidder_id,
+            Some("carol.testnet".to_owned())
+        );
+    }
+
+    #[test]
+    fn cancel_auction_without_bids() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("alice.testnet", 1, START));
+
+        contract.cancel_auction("meme".to_owned());
+
+        assert!(contract.get_auction("meme".to_owned()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot cancel auction which already has bids")]
+    fn cancel_auction_with_bids() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        testing_env!(get_context("alice.testnet", 1, START + 2));
+
+        contract.cancel_auction("meme".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Auction has not ended yet")]
+    fn settle_auction_before_end() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("bob.testnet", 0, END - 1));
+
+        contract.settle_auction("meme".to_owned());
+    }
+
+    #[test]
+    fn settle_auction_transfers_meme_to_winner() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        bid(&mut contract, "carol.testnet", 150, START + 2);
+        testing_env!(get_context("anyone.testnet", 0, END));
+
+        contract.settle_auction("meme".to_owned());
+
+        assert!(contract.get_auction("meme".to_owned()).is_none());
+        let token = contract.nft_token("meme".to_owned()).unwrap();
+        assert_eq!(token.owner_id, "carol.testnet".to_owned());
+        assert_eq!(
+            token.royalty,
+            HashMap::from([("artist.testnet".to_owned(), 1_000)])
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"old_owner_id":"alice.testnet","new_owner_id":"carol.testnet","token_ids":["meme"]}]}"#,
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"auction_settled","data":[{"token_id":"meme","seller_id":"alice.testnet","winner_id":"carol.testnet","price":"150"}]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn settle_auction_without_bids() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("anyone.testnet", 0, END));
+
+        contract.settle_auction("meme".to_owned());
+
+        assert!(contract.get_auction("meme".to_owned()).is_none());
+        assert_eq!(
+            contract.nft_token("meme".to_owned()).unwrap().owner_id,
+            "alice.testnet".to_owned()
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"auction_settled","data":[{"token_id":"meme","seller_id":"alice.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn settle_auction_of_frozen_meme_refunds_bid() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        contract.frozen_memes.insert(&"meme".to_owned());
+        testing_env!(get_context("anyone.testnet", 0, END));
+
+        contract.settle_auction("meme".to_owned());
+
+        assert!(contract.get_auction("meme".to_owned()).is_none());
+        assert_eq!(
+            contract.nft_token("meme".to_owned()).unwrap().owner_id,
+            "alice.testnet".to_owned()
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"auction_cancelled","data":[{"token_id":"meme","seller_id":"alice.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn settle_auction_when_paused() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        contract.paused = true;
+        testing_env!(get_context("anyone.testnet", 0, END));
+
+        contract.settle_auction("meme".to_owned());
+    }
+
+    #[test]
+    fn settle_auction_of_previous_owner_refunds_bid() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+        let mut token = contract.memes_by_id.get(&"meme".to_owned()).unwrap();
+        token.owner_id = "dave.testnet".to_owned();
+        contract.memes_by_id.insert(&"meme".to_owned(), &token);
+        testing_env!(get_context("anyone.testnet", 0, END));
+
+        contract.settle_auction("meme".to_owned());
+
+        assert!(contract.get_auction("meme".to_owned()).is_none());
+        assert_eq!(
+            contract.nft_token("meme".to_owned()).unwrap().owner_id,
+            "dave.testnet".to_owned()
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"auction_cancelled","data":[{"token_id":"meme","seller_id":"alice.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn place_bid_pays_storage_from_deposit() {
+        let mut contract = auction_contract();
+        testing_env!(get_context("bob.testnet", AUCTION_DEPOSIT, START + 1));
+
+        contract.place_bid("meme".to_owned());
+
+        let highest_bid = contract.get_auction("meme".to_owned()).unwrap().highest_bid;
+        assert!(highest_bid.unwrap().0 < AUCTION_DEPOSIT);
+    }
+
+    #[test]
+    fn place_bid_pays_storage_from_storage_balance() {
+        let mut contract = auction_contract();
+        bid(&mut contract, "bob.testnet", 100, START + 1);
+
+        assert_eq!(
+            contract.get_auction("meme".to_owned()).unwrap().highest_bid,
+            Some(U128(100))
+        );
+        assert!(
+            contract
+                .storage_balance_of("bob.testnet".to_owned())
+                .unwrap()
+                .available
+                .0
+                < AUCTION_DEPOSIT
+        );
+    }
+
+    #[test]
+    fn reverted_transfer_call_closes_receiver_auction() {
+        let mut contract = auction_contract();
+        let frog_id = "frog".to_owned();
+        contract.memes_by_id.insert(
+            &frog_id,
+            &MemeToken {
+                owner_id: "bob.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+        contract
+            .meme_metadata_by_id
+            .insert(&frog_id, &Default::default());
+        contract
+            .meme_additional_data_by_id
+            .insert(&frog_id, &Default::default());
+        contract.add_meme_to_owner(&"bob.testnet".to_owned(), &frog_id);
+        testing_env!(get_context("bob.testnet", 1, START));
+        contract.nft_transfer_call(
+            "market.testnet".to_owned(),
+            frog_id.clone(),
+            None,
+            None,
+            "".to_owned(),
+        );
+        testing_env!(get_context("market.testnet", AUCTION_DEPOSIT, START));
+        contract.create_auction(frog_id.clone(), U128(1), END, U128(1));
+
+        testing_env!(
+            get_context("husy.testnet", 0, START),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            vec![PromiseResult::Successful(b"true".to_vec())]
+        );
+        contract.nft_resolve_transfer(
+            "bob.testnet".to_owned(),
+            "market.testnet".to_owned(),
+            frog_id.clone(),
+            Default::default(),
+        );
+
+        assert!(contract.get_auction(frog_id.clone()).is_none());
+        assert_eq!(
+            contract.nft_token(frog_id).unwrap().owner_id,
+            "bob.testnet".to_owned()
+        );
+    }
+}
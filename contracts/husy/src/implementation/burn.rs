@@ -0,0 +1,243 @@
+use near_sdk::{assert_one_yocto, env, near_bindgen};
+
+use crate::{
+    contract::NFTBurn,
+    events::{NftBurnLog, NftEvent},
+    models::{husy::*, meme::MemeTokenId, provenance::ProvenanceEvent},
+    utils::{likes_helpers::discount_likes_state, payment::with_refund},
+};
+
+#[near_bindgen]
+impl NFTBurn for HusyContract {
+    #[payable]
+    fn nft_burn(&mut self, token_id: MemeTokenId) {
+        assert_one_yocto();
+        self.assert_not_paused();
+        self.assert_not_on_auction(&token_id);
+        self.assert_not_frozen(&token_id);
+
+        let sender_id = env::predecessor_account_id();
+        let token = self
+            .memes_by_id
+            .get(&token_id)
+            .expect("Token id is invalid");
+        let authorized_id = if token.owner_id != sender_id {
+            assert!(
+                token.approved_account_ids.contains_key(&sender_id),
+                "Unauthorized"
+            );
+            Some(sender_id)
+        } else {
+            None
+        };
+
+        // Offers refund their own storage to bidders
+        self.refund_offers(&token_id);
+        // Reports and moderation state are paid by contract, not by owner
+        self.resolve_reports(&token_id, true);
+        self.hidden_memes.remove(&token_id);
+        self.remove_remix_links(&token_id);
+        // Listing refunds its own storage to seller
+        self.remove_listing(&token_id);
+        with_refund(|| {
+            self.remove_meme_from_feeds(&token_id);
+            self.memes_by_id.remove(&token_id);
+            self.meme_metadata_by_id.remove(&token_id);
+            self.metadata_revisions.remove(&token_id);
+            self.sold_memes.remove(&token_id);
+            self.remove_edition(&token_id);
+            if let Some(meme_additional_data) = self.meme_additional_data_by_id.remove(&token_id) {
+                let mut global_likes_data = self.global_likes_data.get().unwrap();
+                discount_likes_state(&meme_additional_data, &mut global_likes_data);
+                self.global_likes_data.set(&global_likes_data);
+                if let Some(category_id) = &meme_additional_data.category {
+                    self.remove_meme_from_category(category_id);
+                }
+                self.remove_meme_from_creator(&meme_additional_data.creator_id, &token_id);
+            }
+            self.remove_meme_from_owner(&token.owner_id, &token_id);
+            self.remove_trending_score(&token_id);
+
+            ((), Some(token.owner_id.clone()))
+        });
+        // Provenance outlives meme, its storage was reserved at mint
+        self.record_provenance(
+            &token_id,
+            ProvenanceEvent::Burn,
+            Some(token.owner_id.clone()),
+            None,
+        );
+
+        NftEvent::Burn(vec![NftBurnLog {
+            owner_id: token.owner_id,
+            authorized_id,
+            token_ids: vec![token_id],
+            memo: None,
+        }])
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::json_types::U128;
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, AccountId, VMContext,
+    };
+
+    use crate::contract::{ContractInit, MemeInteraction, NFTEnumeration};
+    use crate::models::meme::MemeToken;
+    use crate::models::meme_additional_data::MemeAdditionalData;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str, attached: u128) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    fn feed_meme(contract: &mut HusyContract, meme_id: &MemeTokenId, owner_id: &AccountId) {
+        contract.memes_by_id.insert(
+            meme_id,
+            &MemeToken {
+                owner_id: owner_id.to_owned(),
+                approved_account_ids: HashMap::from([("approved.testnet".to_owned(), 0)]),
+                next_approval_id: 1,
+                ..Default::default()
+            },
+        );
+        contract
+            .meme_metadata_by_id
+            .insert(meme_id, &Default::default());
+        contract
+            .meme_additional_data_by_id
+            .insert(meme_id, &Default::default());
+        contract.add_meme_to_owner(owner_id, meme_id);
+    }
+
+    #[test]
+    fn nft_burn_by_owner() {
+        let owner_id = "owner.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(&owner_id, 1));
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        feed_meme(&mut contract, &meme_id, &owner_id);
+        feed_meme(&mut contract, &"other_meme.testnet".to_owned(), &owner_id);
+        contract.record_like(&"liker.testnet".to_owned(), &meme_id, 2);
+        contract.record_like(
+            &"liker.testnet".to_owned(),
+            &"other_meme.testnet".to_owned(),
+            1,
+        );
+
+        contract.nft_burn(meme_id.clone());
+
+        assert!(contract.memes_by_id.get(&meme_id).is_none());
+        assert!(contract.meme_metadata_by_id.get(&meme_id).is_none());
+        assert!(contract.meme_additional_data_by_id.get(&meme_id).is_none());
+        assert_eq!(contract.nft_supply_for_owner(owner_id), U128(1));
+        assert_eq!(contract.nft_total_supply(), U128(1));
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_burn","data":[{"owner_id":"owner.testnet","token_ids":["meme.testnet"]}]}"#
+            ]
+        );
+
+        assert_eq!(contract.remove_burned_meme_likes(meme_id.clone(), None), 0);
+        assert!(contract.likers_per_meme.get(&meme_id).is_none());
+        assert!(contract
+            .get_like_view("liker.testnet".to_owned(), meme_id)
+            .is_none());
+        assert!(contract
+            .get_like_view("liker.testnet".to_owned(), "other_meme.testnet".to_owned())
+            .is_some());
+    }
+
+    #[test]
+    fn nft_burn_by_approved_account() {
+        let owner_id = "owner.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context("approved.testnet", 1));
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        feed_meme(&mut contract, &meme_id, &owner_id);
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                category: Some("cats".to_owned()),
+                showed_on_main: true,
+                ..Default::default()
+            },
+        );
+        contract.add_meme_to_feeds(&meme_id);
+
+        contract.nft_burn(meme_id.clone());
+
+        assert!(contract.memes_by_id.get(&meme_id).is_none());
+        assert!(contract.memes_per_owner.get(&owner_id).is_none());
+        assert_eq!(contract.nft_total_supply(), U128(0));
+        assert!(contract.main_page_memes.is_empty());
+        assert!(contract
+            .memes_per_category
+            .get(&"cats".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    fn nft_burn_updates_global_likes_data() {
+        let owner_id = "owner.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        let mut context = get_context(&owner_id, 1);
+        context.block_timestamp = 10;
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        feed_meme(&mut contract, &meme_id, &owner_id);
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                likes: 4,
+                counted_likes: 4,
+                last_counted_like_timestamp: 10,
+                ..Default::default()
+            },
+        );
+        let mut global_likes_data = contract.global_likes_data.get().unwrap();
+        global_likes_data.first_group_sum = 10;
+        global_likes_data.first_group_liked_memes = 2;
+        contract.global_likes_data.set(&global_likes_data);
+
+        contract.nft_burn(meme_id);
+
+        let global_likes_data = contract.global_likes_data.get().unwrap();
+        assert_eq!(global_likes_data.first_group_sum, 6);
+        assert_eq!(global_likes_data.first_group_liked_memes, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn nft_burn_unauthorized() {
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context("someone.testnet", 1));
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        feed_meme(&mut contract, &meme_id, &"owner.testnet".to_owned());
+
+        contract.nft_burn(meme_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn nft_burn_without_deposit() {
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        feed_meme(&mut contract, &meme_id, &"owner.testnet".to_owned());
+
+        contract.nft_burn(meme_id);
+    }
+}
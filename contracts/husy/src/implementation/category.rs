@@ -0,0 +1,364 @@
+use near_sdk::{env, near_bindgen};
+
+use crate::{
+    contract::CategoryRegistry,
+    models::{
+        category::{Category, CategoryId, CategoryView},
+        husy::*,
+        meme::MemeTokenId,
+        role::Role,
+    },
+    utils::category_helpers::{assert_valid_category_id, assert_valid_category_name},
+};
+
+#[near_bindgen]
+impl CategoryRegistry for HusyContract {
+    fn add_category(&mut self, category_id: CategoryId, name: String) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert_valid_category_id(&category_id);
+        assert_valid_category_name(&name);
+        assert!(
+            self.categories.get(&category_id).is_none(),
+            "Category {} already exists",
+            category_id
+        );
+
+        self.categories.insert(
+            &category_id,
+            &Category {
+                name,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn rename_category(&mut self, category_id: CategoryId, name: String) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert_valid_category_name(&name);
+        let mut category = self
+            .categories
+            .get(&category_id)
+            .unwrap_or_else(|| panic!("Category {} does not exist", category_id));
+
+        category.name = name;
+        self.categories.insert(&category_id, &category);
+    }
+
+    fn retire_category(&mut self, category_id: CategoryId) {
+        self.assert_owner_or_role(Role::Moderator);
+        let mut category = self
+            .categories
+            .get(&category_id)
+            .unwrap_or_else(|| panic!("Category {} does not exist", category_id));
+        assert!(!category.retired, "Category {} is retired", category_id);
+
+        category.retired = true;
+        self.categories.insert(&category_id, &category);
+    }
+
+    fn get_category(&self, category_id: CategoryId) -> Option<CategoryView> {
+        self.get_category_view(category_id)
+    }
+
+    fn get_categories(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<CategoryView> {
+        self.categories
+            .keys()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(self.categories.len()) as usize)
+            .filter_map(|category_id| self.get_category_view(category_id))
+            .collect()
+    }
+
+    fn recategorize_meme(&mut self, meme_id: MemeTokenId, category_id: CategoryId) {
+        self.assert_not_paused();
+        let mut meme_additional_data = self
+            .meme_additional_data_by_id
+            .get(&meme_id)
+            .expect("Meme not found");
+        assert_eq!(
+            meme_additional_data.creator_id,
+            env::predecessor_account_id(),
+            "Only meme creator can change its category"
+        );
+        assert!(
+            !meme_additional_data.category_changed,
+            "Meme category can be changed only once"
+        );
+        assert_ne!(
+            meme_additional_data.category.as_ref(),
+            Some(&category_id),
+            "Meme already belongs to category {}",
+            category_id
+        );
+
+        self.add_meme_to_category(&category_id);
+        if let Some(previous_category_id) = &meme_additional_data.category {
+            self.remove_meme_from_category(previous_category_id);
+        }
+        self.remove_meme_from_feeds(&meme_id);
+        self.remove_listing_from_category(&meme_id);
+        meme_additional_data.category = Some(category_id);
+        meme_additional_data.category_changed = true;
+        self.meme_additional_data_by_id
+            .insert(&meme_id, &meme_additional_data);
+        self.add_meme_to_feeds(&meme_id);
+        if self.listings.get(&meme_id).is_some() {
+            self.add_listing_to_category(&meme_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::ContractInit;
+    use crate::models::{listing::Listing, meme_additional_data::MemeAdditionalData};
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .build()
+    }
+
+    fn contract_with_categories() -> HusyContract {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.add_category("cats".to_owned(), "Cats".to_owned());
+        contract.add_category("dogs".to_owned(), "Dogs".to_owned());
+        contract
+    }
+
+    #[test]
+    fn add_rename_and_retire_category() {
+        let mut contract = contract_with_categories();
+
+        contract.rename_category("cats".to_owned(), "Kittens".to_owned());
+        contract.retire_category("dogs".to_owned());
+
+        assert_eq!(
+            contract.get_categories(None, None),
+            vec![
+                CategoryView {
+                    id: "cats".to_owned(),
+                    name: "Kittens".to_owned(),
+                    retired: false,
+                    memes_count: 0,
+                },
+                CategoryView {
+                    id: "dogs".to_owned(),
+                    name: "Dogs".to_owned(),
+                    retired: true,
+                    memes_count: 0,
+                },
+            ]
+        );
+        assert_eq!(
+            contract.get_categories(Some(1), Some(1))[0].id,
+            "dogs".to_owned()
+        );
+    }
+
+    #[test]
+    fn add_category_by_moderator() {
+        let mut contract = contract_with_categories();
+        contract
+            .roles_per_account
+            .insert(&"moderator.testnet".to_owned(), &vec![Role::Moderator]);
+
+        testing_env!(get_context("moderator.testnet"));
+        contract.add_category("frogs".to_owned(), "Frogs".to_owned());
+
+        assert!(contract.get_category("frogs".to_owned()).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner or account with Moderator role")]
+    fn add_category_unauthorized() {
+        let mut contract = contract_with_categories();
+
+        testing_env!(get_context("someone.testnet"));
+        contract.add_category("frogs".to_owned(), "Frogs".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Category cats already exists")]
+    fn add_existing_category() {
+        let mut contract = contract_with_categories();
+
+        contract.add_category("cats".to_owned(), "Other cats".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Category id can contain only lowercase letters")]
+    fn add_category_with_invalid_id() {
+        let mut contract = contract_with_categories();
+
+        contract.add_category("Big Cats".to_owned(), "Big cats".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Category name must have between 1 and 64 characters")]
+    fn add_category_with_empty_name() {
+        let mut contract = contract_with_categories();
+
+        contract.add_category("empty".to_owned(), " ".to_owned());
+    }
+
+    #[test]
+    fn recategorize_meme_once() {
+        let mut contract = contract_with_categories();
+        let meme_id = "meme.testnet".to_owned();
+        contract.add_meme_to_category(&"cats".to_owned());
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                category: Some("cats".to_owned()),
+                ..Default::default()
+            },
+        );
+        contract.add_meme_to_feeds(&meme_id);
+
+        testing_env!(get_context("creator.testnet"));
+        contract.recategorize_meme(meme_id.clone(), "dogs".to_owned());
+
+        let meme_additional_data = contract.meme_additional_data_by_id.get(&meme_id).unwrap();
+        assert_eq!(meme_additional_data.category, Some("dogs".to_owned()));
+        assert!(contract
+            .memes_per_category
+            .get(&"cats".to_owned())
+            .is_none());
+        assert!(contract
+            .memes_per_category
+            .get(&"dogs".to_owned())
+            .unwrap()
+            .contains(&meme_id));
+        assert!(meme_additional_data.category_changed);
+        assert_eq!(
+            contract
+                .get_category("cats".to_owned())
+                .unwrap()
+                .memes_count,
+            0
+        );
+        assert_eq!(
+            contract
+                .get_category("dogs".to_owned())
+                .unwrap()
+                .memes_count,
+            1
+        );
+    }
+
+    #[test]
+    fn recategorize_listed_meme_moves_listing_index() {
+        let mut contract = contract_with_categories();
+        let meme_id = "meme.testnet".to_owned();
+        contract.add_meme_to_category(&"cats".to_owned());
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                category: Some("cats".to_owned()),
+                ..Default::default()
+            },
+        );
+        contract.listings.insert(
+            &meme_id,
+            &Listing {
+                seller_id: "creator.testnet".to_owned(),
+                price: 100,
+                listed_at: 0,
+            },
+        );
+        contract.add_listing_to_category(&meme_id);
+
+        testing_env!(get_context("creator.testnet"));
+        contract.recategorize_meme(meme_id.clone(), "dogs".to_owned());
+
+        assert!(contract
+            .listings_per_category
+            .get(&"cats".to_owned())
+            .is_none());
+        assert!(contract
+            .listings_per_category
+            .get(&"dogs".to_owned())
+            .unwrap()
+            .contains(&meme_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme category can be changed only once")]
+    fn recategorize_meme_twice() {
+        let mut contract = contract_with_categories();
+        let meme_id = "meme.testnet".to_owned();
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+
+        testing_env!(get_context("creator.testnet"));
+        contract.recategorize_meme(meme_id.clone(), "dogs".to_owned());
+        contract.recategorize_meme(meme_id, "cats".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only meme creator can change its category")]
+    fn recategorize_meme_not_creator() {
+        let mut contract = contract_with_categories();
+        let meme_id = "meme.testnet".to_owned();
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+
+        testing_env!(get_context("someone.testnet"));
+        contract.recategorize_meme(meme_id, "dogs".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Category dogs is retired")]
+    fn recategorize_meme_to_retired_category() {
+        let mut contract = contract_with_categories();
+        contract.retire_category("dogs".to_owned());
+        let meme_id = "meme.testnet".to_owned();
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+
+        testing_env!(get_context("creator.testnet"));
+        contract.recategorize_meme(meme_id, "dogs".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn recategorize_meme_when_paused() {
+        let mut contract = contract_with_categories();
+        let meme_id = "meme.testnet".to_owned();
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                creator_id: "creator.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+        contract.paused = true;
+
+        testing_env!(get_context("creator.testnet"));
+        contract.recategorize_meme(meme_id, "dogs".to_owned());
+    }
+}
@@ -0,0 +1,399 @@
+use near_sdk::{
+    assert_one_yocto, borsh::BorshSerialize, collections::UnorderedMap, env, near_bindgen,
+};
+
+use crate::{
+    contract::Comments,
+    events::{CommentAddedLog, CommentDeletedLog, HusyEvent},
+    models::{
+        comment::{Comment, CommentId, CommentView, MAX_COMMENTS_REMOVED_PER_CALL},
+        husy::*,
+        meme::MemeTokenId,
+        role::Role,
+        storage::StorageKey,
+    },
+    utils::{comment_helpers::assert_valid_comment_text, hashing::hash_meme_id},
+};
+
+#[near_bindgen]
+impl Comments for HusyContract {
+    /// Storage is paid by commenter from storage balance or attached deposit
+    #[payable]
+    fn add_comment(
+        &mut self,
+        meme_id: MemeTokenId,
+        text: String,
+        parent_id: Option<CommentId>,
+    ) -> CommentId {
+        self.assert_not_paused();
+        assert_valid_comment_text(&text);
+        assert!(self.memes_by_id.get(&meme_id).is_some(), "Meme not found");
+        assert!(!self.is_meme_hidden(&meme_id), "Meme is hidden");
+
+        let mut comments = self.comments_per_meme.get(&meme_id).unwrap_or_else(|| {
+            UnorderedMap::new(
+                StorageKey::CommentsPerMemeInner {
+                    meme_id_hash: hash_meme_id(&meme_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        if let Some(parent_id) = &parent_id {
+            let parent = comments.get(parent_id).expect("Parent comment not found");
+            assert!(parent.parent_id.is_none(), "Cannot reply to reply");
+        }
+
+        let author_id = env::predecessor_account_id();
+        let comment_id = self.next_comment_id;
+        self.next_comment_id += 1;
+
+        let initial_storage_usage = env::storage_usage();
+        comments.insert(
+            &comment_id,
+            &Comment {
+                author_id: author_id.clone(),
+                text,
+                created_at: env::block_timestamp(),
+                parent_id,
+            },
+        );
+        self.comments_per_meme.insert(&meme_id, &comments);
+        self.add_storage_record(&author_id);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::CommentAdded(vec![CommentAddedLog {
+            comment_id,
+            meme_id,
+            author_id,
+            parent_id,
+        }])
+        .emit();
+
+        comment_id
+    }
+
+    /// Replies of deleted comment are kept
+    #[payable]
+    fn delete_comment(&mut self, meme_id: MemeTokenId, comment_id: CommentId) {
+        assert_one_yocto();
+        let comment = self
+            .comments_per_meme
+            .get(&meme_id)
+            .and_then(|comments| comments.get(&comment_id))
+            .expect("Comment not found");
+        let predecessor_account_id = env::predecessor_account_id();
+        assert!(
+            predecessor_account_id == comment.author_id
+                || self.is_owner(&predecessor_account_id)
+                || self.account_has_role(&predecessor_account_id, &Role::Moderator),
+            "Only comment author or moderator can delete comment"
+        );
+
+        self.remove_comment(&meme_id, &comment_id);
+
+        HusyEvent::CommentDeleted(vec![CommentDeletedLog {
+            comment_id,
+            meme_id,
+            author_id: comment.author_id,
+            deleted_by: predecessor_account_id,
+        }])
+        .emit();
+    }
+
+    fn get_comments(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<CommentView> {
+        let comments = match self.comments_per_meme.get(&meme_id) {
+            Some(comments) => comments,
+            None => return vec![],
+        };
+
+        comments
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(comments.len()) as usize)
+            .map(|(comment_id, comment)| {
+                self.get_comment_view(meme_id.clone(), comment_id, comment)
+            })
+            .collect()
+    }
+
+    /// Burn leaves comments in place, anyone can remove them in batches
+    /// afterwards and their storage goes back to authors. Returns count
+    /// of comments left
+    fn remove_burned_meme_comments(&mut self, meme_id: MemeTokenId, limit: Option<u64>) -> u64 {
+        assert!(
+            !self.memes_by_id.contains_key(&meme_id),
+            "Meme is not burned"
+        );
+        let limit = limit
+            .unwrap_or(MAX_COMMENTS_REMOVED_PER_CALL)
+            .min(MAX_COMMENTS_REMOVED_PER_CALL);
+
+        self.remove_meme_comments(&meme_id, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{ContractInit, MintNFT, Moderation, NFTBurn, NFTTokenCore};
+    use crate::models::comment::MAX_COMMENT_LENGTH;
+    use crate::models::meme::MemeToken;
+
+    use super::*;
+
+    const DEPOSIT: Balance = 10_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(42)
+            .build()
+    }
+
+    fn commented_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"moderator.testnet".to_owned(), &vec![Role::Moderator]);
+        let meme_id = "cat".to_owned();
+        contract.memes_by_id.insert(
+            &meme_id,
+            &MemeToken {
+                owner_id: "alice.testnet".to_owned(),
+                ..Default::default()
+            },
+        );
+        contract
+            .meme_metadata_by_id
+            .insert(&meme_id, &Default::default());
+        contract
+            .meme_additional_data_by_id
+            .insert(&meme_id, &Default::default());
+        contract.add_meme_to_owner(&"alice.testnet".to_owned(), &meme_id);
+
+        contract
+    }
+
+    fn comment(
+        contract: &mut HusyContract,
+        author_id: &str,
+        text: &str,
+        parent_id: Option<CommentId>,
+    ) -> CommentId {
+        testing_env!(get_context(author_id, DEPOSIT));
+        contract.add_comment("cat".to_owned(), text.to_owned(), parent_id)
+    }
+
+    #[test]
+    fn add_comment_and_reply() {
+        let mut contract = commented_contract();
+
+        let comment_id = comment(&mut contract, "bob.testnet", "Nice", None);
+        let reply_id = comment(&mut contract, "carol.testnet", "Agreed", Some(comment_id));
+
+        assert_eq!(
+            contract.get_comments("cat".to_owned(), None, None),
+            vec![
+                CommentView {
+                    id: 0,
+                    meme_id: "cat".to_owned(),
+                    author_id: "bob.testnet".to_owned(),
+                    text: "Nice".to_owned(),
+                    created_at: 42,
+                    parent_id: None,
+                },
+                CommentView {
+                    id: reply_id,
+                    meme_id: "cat".to_owned(),
+                    author_id: "carol.testnet".to_owned(),
+                    text: "Agreed".to_owned(),
+                    created_at: 42,
+                    parent_id: Some(0),
+                },
+            ]
+        );
+        assert_eq!(
+            contract.get_comments("cat".to_owned(), Some(1), Some(1))[0].id,
+            1
+        );
+        assert_eq!(contract.nft_token("cat".to_owned()).unwrap().comments, 2);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"comment_added","data":[{"comment_id":1,"meme_id":"cat","author_id":"carol.testnet","parent_id":0}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot reply to reply")]
+    fn reply_to_reply() {
+        let mut contract = commented_contract();
+        let comment_id = comment(&mut contract, "bob.testnet", "Nice", None);
+        let reply_id = comment(&mut contract, "carol.testnet", "Agreed", Some(comment_id));
+
+        comment(&mut contract, "bob.testnet", "Thanks", Some(reply_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Parent comment not found")]
+    fn reply_to_missing_comment() {
+        let mut contract = commented_contract();
+
+        comment(&mut contract, "bob.testnet", "Nice", Some(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "Comment must have between 1 and 500 characters")]
+    fn add_too_long_comment() {
+        let mut contract = commented_contract();
+
+        comment(
+            &mut contract,
+            "bob.testnet",
+            &"a".repeat(MAX_COMMENT_LENGTH + 1),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "or deposit it with storage_deposit to cover storage")]
+    fn add_comment_without_storage_deposit() {
+        let mut contract = commented_contract();
+        testing_env!(get_context("bob.testnet", 0));
+
+        contract.add_comment("cat".to_owned(), "Nice".to_owned(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is hidden")]
+    fn add_comment_to_hidden_meme() {
+        let mut contract = commented_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.hide_meme("cat".to_owned(), None);
+
+        comment(&mut contract, "bob.testnet", "Nice", None);
+    }
+
+    #[test]
+    fn delete_comment_by_author() {
+        let mut contract = commented_contract();
+        let comment_id = comment(&mut contract, "bob.testnet", "Nice", None);
+        comment(&mut contract, "carol.testnet", "Agreed", Some(comment_id));
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.delete_comment("cat".to_owned(), comment_id);
+
+        let comments = contract.get_comments("cat".to_owned(), None, None);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].parent_id, Some(comment_id));
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"comment_deleted","data":[{"comment_id":0,"meme_id":"cat","author_id":"bob.testnet","deleted_by":"bob.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn delete_comment_by_moderator() {
+        let mut contract = commented_contract();
+        let comment_id = comment(&mut contract, "bob.testnet", "Nice", None);
+        testing_env!(get_context("moderator.testnet", 1));
+
+        contract.delete_comment("cat".to_owned(), comment_id);
+
+        assert!(contract.comments_per_meme.get(&"cat".to_owned()).is_none());
+        assert_eq!(contract.nft_token("cat".to_owned()).unwrap().comments, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only comment author or moderator can delete comment")]
+    fn delete_comment_unauthorized() {
+        let mut contract = commented_contract();
+        let comment_id = comment(&mut contract, "bob.testnet", "Nice", None);
+        testing_env!(get_context("alice.testnet", 1));
+
+        contract.delete_comment("cat".to_owned(), comment_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Comment not found")]
+    fn delete_missing_comment() {
+        let mut contract = commented_contract();
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.delete_comment("cat".to_owned(), 0);
+    }
+
+    #[test]
+    fn remove_burned_meme_comments_in_batches() {
+        let mut contract = commented_contract();
+        comment(&mut contract, "bob.testnet", "Nice", None);
+        comment(&mut contract, "carol.testnet", "Agreed", Some(0));
+        comment(&mut contract, "bob.testnet", "Indeed", Some(0));
+        testing_env!(get_context("alice.testnet", 1));
+        contract.nft_burn("cat".to_owned());
+        testing_env!(get_context("anyone.testnet", 0));
+
+        assert_eq!(
+            contract.remove_burned_meme_comments("cat".to_owned(), Some(2)),
+            1
+        );
+        assert_eq!(contract.get_comments("cat".to_owned(), None, None).len(), 1);
+        assert_eq!(
+            contract.remove_burned_meme_comments("cat".to_owned(), Some(2)),
+            0
+        );
+        assert!(contract.comments_per_meme.get(&"cat".to_owned()).is_none());
+        assert!(contract
+            .storage_records_per_account
+            .get(&"bob.testnet".to_owned())
+            .is_none());
+        assert!(contract
+            .storage_records_per_account
+            .get(&"carol.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Comments of burned meme must be removed first")]
+    fn nft_mint_burned_meme_with_comments() {
+        let mut contract = commented_contract();
+        comment(&mut contract, "bob.testnet", "Nice", None);
+        testing_env!(get_context("alice.testnet", 1));
+        contract.nft_burn("cat".to_owned());
+
+        testing_env!(get_context("owner.testnet", DEPOSIT));
+        contract.nft_mint(
+            "cat".to_owned(),
+            Default::default(),
+            "owner.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not burned")]
+    fn remove_burned_meme_comments_of_existing_meme() {
+        let mut contract = commented_contract();
+        comment(&mut contract, "bob.testnet", "Nice", None);
+
+        contract.remove_burned_meme_comments("cat".to_owned(), None);
+    }
+}
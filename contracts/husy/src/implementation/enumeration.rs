@@ -8,15 +8,17 @@ use crate::{
 #[near_bindgen]
 impl NFTEnumeration for HusyContract {
     fn nft_total_supply(&self) -> U128 {
-        U128(self.meme_metadata_by_id.len().into())
+        U128(self.meme_additional_data_by_id.len().into())
     }
 
     fn nft_tokens(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<MemeTokenView> {
-        self.meme_metadata_by_id
-            .iter()
+        // Every meme has additional data, while editions share metadata of their series
+        self.meme_additional_data_by_id
+            .keys()
+            .filter(|key| !self.is_meme_hidden(key))
             .skip(from_index.unwrap_or(U128(0)).0 as usize)
-            .take(limit.unwrap_or(self.meme_metadata_by_id.len()) as usize)
-            .filter_map(|(key, value)| self.get_meme_view(key, Some(value)))
+            .take(limit.unwrap_or(self.meme_additional_data_by_id.len()) as usize)
+            .filter_map(|key| self.get_meme_view(key, None))
             .collect()
     }
 
@@ -41,7 +43,7 @@ impl NFTEnumeration for HusyContract {
         memes
             .iter()
             .skip(from_index.unwrap_or(U128(0)).0 as usize)
-            .take(limit.unwrap_or(self.meme_metadata_by_id.len()) as usize)
+            .take(limit.unwrap_or(memes.len()) as usize)
             .filter_map(|id| self.get_meme_view(id, None))
             .collect()
     }
@@ -99,6 +101,9 @@ mod test {
             contract
                 .meme_metadata_by_id
                 .insert(id, &meme_token_metadata.to_owned());
+            contract
+                .meme_additional_data_by_id
+                .insert(id, &Default::default());
         }
 
         let result = contract.nft_total_supply();
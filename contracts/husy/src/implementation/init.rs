@@ -1,17 +1,24 @@
 use near_sdk::borsh::BorshSerialize;
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap};
-use near_sdk::{near_bindgen, AccountId};
+use near_sdk::collections::{LazyOption, LookupMap, LookupSet, UnorderedMap, UnorderedSet};
+use near_sdk::{env, near_bindgen, AccountId};
 
 use crate::contract::ContractInit;
 use crate::models::global_likes_data::GlobalLikesData;
+use crate::models::like_revenue_split::LikeRevenueSplit;
+use crate::models::likes_config::LikesConfig;
+use crate::models::remix::DEFAULT_REMIX_ROYALTY;
+use crate::models::state_version::CURRENT_STATE_VERSION;
 use crate::models::storage::StorageKey;
+use crate::models::trending::TRENDING_BUCKET_DURATION;
 use crate::models::{husy::*, husy_metadata::HusyNFTContractMetadata};
+use crate::utils::metadata_validation::assert_valid_contract_metadata;
+use crate::utils::migration::write_state_version;
 
-#[near_bindgen]
-impl ContractInit for HusyContract {
-    #[init]
-    fn new(owner_id: AccountId, metadata: HusyNFTContractMetadata) -> Self {
-        Self {
+impl HusyContract {
+    /// Builds empty state without validating metadata,
+    /// so legacy metadata can be migrated as is
+    pub(crate) fn internal_new(owner_id: AccountId, metadata: HusyNFTContractMetadata) -> Self {
+        let mut this = Self {
             owner_id,
             memes_per_owner: LookupMap::new(StorageKey::MemesPerOwner.try_to_vec().unwrap()),
             memes_by_id: LookupMap::new(StorageKey::MemessById.try_to_vec().unwrap()),
@@ -19,7 +26,7 @@ impl ContractInit for HusyContract {
                 StorageKey::MemeMetadataById.try_to_vec().unwrap(),
             ),
             meme_additional_data_by_id: UnorderedMap::new(
-                StorageKey::MemeAdditionalData.try_to_vec().unwrap(),
+                StorageKey::MemeAdditionalDataV1.try_to_vec().unwrap(),
             ),
             metadata: LazyOption::new(
                 StorageKey::HusyContractMetadata.try_to_vec().unwrap(),
@@ -29,7 +36,83 @@ impl ContractInit for HusyContract {
                 StorageKey::GlobalLikesData.try_to_vec().unwrap(),
                 Some(&GlobalLikesData::new()),
             ),
-        }
+            likes_per_account: LookupMap::new(StorageKey::LikesPerAccount.try_to_vec().unwrap()),
+            likers_per_meme: LookupMap::new(StorageKey::LikersPerMeme.try_to_vec().unwrap()),
+            like_revenue_split: LazyOption::new(
+                StorageKey::LikeRevenueSplit.try_to_vec().unwrap(),
+                Some(&LikeRevenueSplit::new()),
+            ),
+            treasury_balance: 0,
+            reward_pool_balance: 0,
+            likes_config: LazyOption::new(
+                StorageKey::LikesConfig.try_to_vec().unwrap(),
+                Some(&LikesConfig::new()),
+            ),
+            pending_owner_id: None,
+            paused: false,
+            roles_per_account: UnorderedMap::new(StorageKey::RolesPerAccount.try_to_vec().unwrap()),
+            categories: UnorderedMap::new(StorageKey::Categories.try_to_vec().unwrap()),
+            memes_per_category: LookupMap::new(StorageKey::MemesPerCategory.try_to_vec().unwrap()),
+            main_page_memes: UnorderedSet::new(StorageKey::MainPageMemes.try_to_vec().unwrap()),
+            main_page_memes_per_category: LookupMap::new(
+                StorageKey::MainPageMemesPerCategory.try_to_vec().unwrap(),
+            ),
+            trending_scores: LookupMap::new(StorageKey::TrendingScores.try_to_vec().unwrap()),
+            trending_buckets: LookupMap::new(StorageKey::TrendingBuckets.try_to_vec().unwrap()),
+            first_trending_bucket: env::block_timestamp() / TRENDING_BUCKET_DURATION,
+            storage_balances: LookupMap::new(StorageKey::StorageBalances.try_to_vec().unwrap()),
+            account_storage_usage: 0,
+            listings: UnorderedMap::new(StorageKey::Listings.try_to_vec().unwrap()),
+            auctions: UnorderedMap::new(StorageKey::Auctions.try_to_vec().unwrap()),
+            offers_per_meme: LookupMap::new(StorageKey::OffersPerMeme.try_to_vec().unwrap()),
+            offers_per_bidder: LookupMap::new(StorageKey::OffersPerBidder.try_to_vec().unwrap()),
+            reports: UnorderedMap::new(StorageKey::Reports.try_to_vec().unwrap()),
+            reports_per_meme: LookupMap::new(StorageKey::ReportsPerMeme.try_to_vec().unwrap()),
+            next_report_id: 0,
+            hidden_memes: UnorderedSet::new(StorageKey::HiddenMemes.try_to_vec().unwrap()),
+            frozen_memes: UnorderedSet::new(StorageKey::FrozenMemes.try_to_vec().unwrap()),
+            comments_per_meme: LookupMap::new(StorageKey::CommentsPerMeme.try_to_vec().unwrap()),
+            next_comment_id: 0,
+            remix_royalty: DEFAULT_REMIX_ROYALTY,
+            remix_parents: LookupMap::new(StorageKey::RemixParents.try_to_vec().unwrap()),
+            remixes_per_meme: LookupMap::new(StorageKey::RemixesPerMeme.try_to_vec().unwrap()),
+            series: UnorderedMap::new(StorageKey::Series.try_to_vec().unwrap()),
+            next_series_id: 0,
+            editions_per_series: LookupMap::new(
+                StorageKey::EditionsPerSeries.try_to_vec().unwrap(),
+            ),
+            creator_keys: LookupMap::new(StorageKey::CreatorKeys.try_to_vec().unwrap()),
+            redeemed_vouchers: LookupSet::new(StorageKey::RedeemedVouchers.try_to_vec().unwrap()),
+            sold_memes: LookupSet::new(StorageKey::SoldMemes.try_to_vec().unwrap()),
+            metadata_revisions: LookupMap::new(StorageKey::MetadataRevisions.try_to_vec().unwrap()),
+            resolve_relative_uris: false,
+            memes_per_creator: LookupMap::new(StorageKey::MemesPerCreator.try_to_vec().unwrap()),
+            provenance_per_meme: LookupMap::new(
+                StorageKey::ProvenancePerMeme.try_to_vec().unwrap(),
+            ),
+            storage_records_per_account: LookupMap::new(
+                StorageKey::StorageRecordsPerAccount.try_to_vec().unwrap(),
+            ),
+            owner_feeds: LookupMap::new(StorageKey::OwnerFeeds.try_to_vec().unwrap()),
+            legacy_meme_additional_data: None,
+            listings_per_category: LookupMap::new(
+                StorageKey::ListingsPerCategory.try_to_vec().unwrap(),
+            ),
+        };
+        this.measure_account_storage_usage();
+        write_state_version(&CURRENT_STATE_VERSION);
+
+        this
+    }
+}
+
+#[near_bindgen]
+impl ContractInit for HusyContract {
+    #[init]
+    fn new(owner_id: AccountId, metadata: HusyNFTContractMetadata) -> Self {
+        assert_valid_contract_metadata(&metadata);
+
+        Self::internal_new(owner_id, metadata)
     }
 
     #[init]
@@ -0,0 +1,179 @@
+use near_sdk::{json_types::U128, near_bindgen};
+
+use crate::{
+    contract::LikesConfiguration,
+    models::{husy::*, likes_config::LikesConfig},
+};
+
+const MAX_MAIN_AVERAGE_FACTOR: u32 = 100_000;
+const MIN_TIME_TO_SWITCH_MODE_LOWER_BOUND: u64 = 3_600_000_000_000;
+const MIN_TRENDING_HALF_LIFE: u64 = 60_000_000_000;
+
+#[near_bindgen]
+impl LikesConfiguration for HusyContract {
+    fn get_likes_config(&self) -> LikesConfig {
+        self.likes_config.get().unwrap()
+    }
+
+    fn set_yocto_near_per_like(&mut self, yocto_near_per_like: U128) {
+        self.assert_owner();
+        assert!(
+            yocto_near_per_like.0 > 0,
+            "Price for like must be greater than 0"
+        );
+
+        let mut config = self.likes_config.get().unwrap();
+        config.yocto_near_per_like = yocto_near_per_like;
+        self.likes_config.set(&config);
+    }
+
+    fn set_main_average_factor(&mut self, main_average_factor: u32) {
+        self.assert_owner();
+        assert!(
+            main_average_factor > 0 && main_average_factor <= MAX_MAIN_AVERAGE_FACTOR,
+            "Main average factor must be between 1 and {}",
+            MAX_MAIN_AVERAGE_FACTOR
+        );
+
+        let mut config = self.likes_config.get().unwrap();
+        config.main_average_factor = main_average_factor;
+        self.likes_config.set(&config);
+    }
+
+    fn set_min_likes(&mut self, min_likes: u64) {
+        self.assert_owner();
+        assert!(min_likes > 0, "Min likes must be greater than 0");
+
+        let mut config = self.likes_config.get().unwrap();
+        config.min_likes = min_likes;
+        self.likes_config.set(&config);
+    }
+
+    fn set_min_time_to_switch_mode(&mut self, min_time_to_switch_mode: u64) {
+        self.assert_owner();
+        assert!(
+            min_time_to_switch_mode >= MIN_TIME_TO_SWITCH_MODE_LOWER_BOUND,
+            "Min time to switch mode cannot be shorter than {} nanoseconds",
+            MIN_TIME_TO_SWITCH_MODE_LOWER_BOUND
+        );
+
+        let mut config = self.likes_config.get().unwrap();
+        config.min_time_to_switch_mode = min_time_to_switch_mode;
+        self.likes_config.set(&config);
+    }
+
+    fn set_trending_half_life(&mut self, trending_half_life: u64) {
+        self.assert_owner();
+        assert!(
+            trending_half_life >= MIN_TRENDING_HALF_LIFE,
+            "Trending half-life cannot be shorter than {} nanoseconds",
+            MIN_TRENDING_HALF_LIFE
+        );
+
+        let mut config = self.likes_config.get().unwrap();
+        config.trending_half_life = trending_half_life;
+        self.likes_config.set(&config);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::{ContractInit, MemeInteraction};
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .build()
+    }
+
+    #[test]
+    fn default_likes_config() {
+        testing_env!(get_context("owner.testnet"));
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        assert_eq!(contract.get_likes_config(), LikesConfig::new());
+    }
+
+    #[test]
+    fn set_likes_config_values() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_yocto_near_per_like(U128(1_000));
+        contract.set_main_average_factor(12_000);
+        contract.set_min_likes(10);
+        contract.set_min_time_to_switch_mode(86_400_000_000_000);
+        contract.set_trending_half_life(3_600_000_000_000);
+
+        assert_eq!(
+            contract.get_likes_config(),
+            LikesConfig {
+                yocto_near_per_like: U128(1_000),
+                main_average_factor: 12_000,
+                min_likes: 10,
+                min_time_to_switch_mode: 86_400_000_000_000,
+                trending_half_life: 3_600_000_000_000,
+            }
+        );
+        assert_eq!(contract.yocto_near_price_for_like(), 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn set_min_likes_not_owner() {
+        testing_env!(get_context("someone.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_min_likes(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Price for like must be greater than 0")]
+    fn set_yocto_near_per_like_zero() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_yocto_near_per_like(U128(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Main average factor must be between 1 and 100000")]
+    fn set_main_average_factor_too_big() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_main_average_factor(MAX_MAIN_AVERAGE_FACTOR + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Min likes must be greater than 0")]
+    fn set_min_likes_zero() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_min_likes(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Min time to switch mode cannot be shorter than")]
+    fn set_min_time_to_switch_mode_too_short() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_min_time_to_switch_mode(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trending half-life cannot be shorter than")]
+    fn set_trending_half_life_too_short() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_trending_half_life(1);
+    }
+}
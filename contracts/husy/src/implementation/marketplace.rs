@@ -0,0 +1,574 @@
+use near_sdk::{assert_one_yocto, env, json_types::U128, near_bindgen, AccountId, Promise};
+
+use crate::{
+    contract::Marketplace,
+    events::{HusyEvent, MemeDelistedLog, MemeListingLog, MemeSoldLog},
+    models::{
+        category::CategoryId,
+        husy::*,
+        listing::{Listing, ListingView},
+        meme::MemeTokenId,
+    },
+    utils::payment::{pay_out, refund_approved_account_ids},
+};
+
+#[near_bindgen]
+impl Marketplace for HusyContract {
+    #[payable]
+    fn list_meme(&mut self, token_id: MemeTokenId, price: U128) {
+        self.assert_not_paused();
+        assert!(price.0 > 0, "Price must be greater than 0");
+        let token = self.assert_meme_owner(&token_id);
+        self.assert_not_on_auction(&token_id);
+        assert!(
+            self.get_active_listing(&token_id).is_none(),
+            "Meme is already listed"
+        );
+        // Listing left by previous owner is void
+        self.remove_listing(&token_id);
+
+        let initial_storage_usage = env::storage_usage();
+        self.listings.insert(
+            &token_id,
+            &Listing {
+                seller_id: token.owner_id.clone(),
+                price: price.0,
+                listed_at: env::block_timestamp(),
+            },
+        );
+        self.add_listing_to_category(&token_id);
+        self.add_storage_record(&token.owner_id);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::MemeListed(vec![MemeListingLog {
+            token_id,
+            owner_id: token.owner_id,
+            price,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn update_price(&mut self, token_id: MemeTokenId, price: U128) {
+        assert_one_yocto();
+        assert!(price.0 > 0, "Price must be greater than 0");
+        self.assert_meme_owner(&token_id);
+        let (mut listing, token) = self
+            .get_active_listing(&token_id)
+            .expect("Meme is not listed");
+
+        listing.price = price.0;
+        self.listings.insert(&token_id, &listing);
+
+        HusyEvent::ListingPriceUpdated(vec![MemeListingLog {
+            token_id,
+            owner_id: token.owner_id,
+            price,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn delist(&mut self, token_id: MemeTokenId) {
+        assert_one_yocto();
+        let token = self.assert_meme_owner(&token_id);
+
+        let initial_storage_usage = env::storage_usage();
+        self.remove_listing(&token_id).expect("Meme is not listed");
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::MemeDelisted(vec![MemeDelistedLog {
+            token_id,
+            owner_id: token.owner_id,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn buy_meme(&mut self, token_id: MemeTokenId) {
+        self.assert_not_paused();
+        let (listing, token) = self
+            .get_active_listing(&token_id)
+            .expect("Meme is not listed");
+        let attached = env::attached_deposit();
+        assert!(
+            attached >= listing.price,
+            "Not enought deposit attached. You need at least: {} yoctoNEAR",
+            listing.price
+        );
+
+        let buyer_id = env::predecessor_account_id();
+        assert_ne!(buyer_id, token.owner_id, "Cannot buy own meme");
+
+        // Buyer pays for storage meme takes in its owner index
+        let initial_storage_usage = env::storage_usage();
+        self.add_meme_to_owner(&buyer_id, &token_id);
+        let refund =
+            self.pay_for_storage(&buyer_id, initial_storage_usage, attached - listing.price);
+
+        let payout = self.get_meme_payout(token_id.clone(), U128(listing.price), u32::MAX);
+        let previous =
+            self.internal_meme_transfer(token_id.clone(), token, buyer_id.clone(), None, None);
+        refund_approved_account_ids(previous.owner_id.clone(), &previous.approved_account_ids);
+        pay_out(&payout);
+        self.mark_meme_sold(&token_id, listing.price);
+
+        HusyEvent::MemeSold(vec![MemeSoldLog {
+            token_id,
+            seller_id: previous.owner_id,
+            buyer_id: buyer_id.clone(),
+            price: U128(listing.price),
+        }])
+        .emit();
+
+        if refund > 0 {
+            Promise::new(buyer_id).transfer(refund);
+        }
+    }
+
+    fn get_listing(&self, token_id: MemeTokenId) -> Option<ListingView> {
+        self.get_listing_view(token_id)
+    }
+
+    fn get_listings(
+        &self,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+        category: Option<CategoryId>,
+        owner: Option<AccountId>,
+    ) -> Vec<ListingView> {
+        self.get_listing_ids(from_index.unwrap_or(0) as u64, limit, &category, &owner)
+            .into_iter()
+            .filter_map(|token_id| self.get_listing_view(token_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, PromiseResult, VMContext,
+    };
+
+    use crate::contract::{ContractInit, NFTBurn, NFTTokenCore, StorageManagement};
+    use crate::models::meme::MemeToken;
+    use crate::models::meme_additional_data::MemeAdditionalData;
+
+    use super::*;
+
+    const LISTING_DEPOSIT: Balance = 10_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(42)
+            .build()
+    }
+
+    fn feed_memes(contract: &mut HusyContract, memes: &[(&str, &str, Option<&str>)]) {
+        for (meme_id, owner_id, category) in memes {
+            let meme_id = meme_id.to_string();
+            contract.memes_by_id.insert(
+                &meme_id,
+                &MemeToken {
+                    owner_id: owner_id.to_string(),
+                    approved_account_ids: HashMap::from([("market.testnet".to_owned(), 0)]),
+                    next_approval_id: 1,
+                    royalty: HashMap::from([("artist.testnet".to_owned(), 1_000)]),
+                },
+            );
+            contract
+                .meme_metadata_by_id
+                .insert(&meme_id, &Default::default());
+            contract.meme_additional_data_by_id.insert(
+                &meme_id,
+                &MemeAdditionalData {
+                    category: category.map(|category| category.to_owned()),
+                    ..Default::default()
+                },
+            );
+            contract.add_meme_to_owner(&owner_id.to_string(), &meme_id);
+        }
+    }
+
+    fn listed_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        feed_memes(
+            &mut contract,
+            &[
+                ("cat", "alice.testnet", Some("cats")),
+                ("dog", "alice.testnet", Some("dogs")),
+                ("kitten", "bob.testnet", Some("cats")),
+                ("frog", "bob.testnet", None),
+            ],
+        );
+        for (meme_id, owner_id, price) in [
+            ("cat", "alice.testnet", 100),
+            ("dog", "alice.testnet", 200),
+            ("kitten", "bob.testnet", 300),
+        ] {
+            testing_env!(get_context(owner_id, LISTING_DEPOSIT));
+            contract.list_meme(meme_id.to_owned(), U128(price));
+        }
+
+        contract
+    }
+
+    fn listed_ids(listings: Vec<ListingView>) -> Vec<MemeTokenId> {
+        listings
+            .into_iter()
+            .map(|listing| listing.token_id)
+            .collect()
+    }
+
+    #[test]
+    fn list_meme_success() {
+        let contract = listed_contract();
+
+        assert_eq!(
+            contract.get_listing("cat".to_owned()),
+            Some(ListingView {
+                token_id: "cat".to_owned(),
+                owner_id: "alice.testnet".to_owned(),
+                price: U128(100),
+                listed_at: 42,
+                category: Some("cats".to_owned()),
+            })
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_listed","data":[{"token_id":"kitten","owner_id":"bob.testnet","price":"300"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Predecessor must be meme owner")]
+    fn list_meme_not_owner() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", LISTING_DEPOSIT));
+
+        contract.list_meme("frog".to_owned(), U128(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is already listed")]
+    fn list_meme_twice() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", LISTING_DEPOSIT));
+
+        contract.list_meme("cat".to_owned(), U128(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Price must be greater than 0")]
+    fn list_meme_for_free() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("bob.testnet", LISTING_DEPOSIT));
+
+        contract.list_meme("frog".to_owned(), U128(0));
+    }
+
+    #[test]
+    fn update_price_success() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 1));
+
+        contract.update_price("cat".to_owned(), U128(150));
+
+        assert_eq!(
+            contract.get_listing("cat".to_owned()).unwrap().price,
+            U128(150)
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"listing_price_updated","data":[{"token_id":"cat","owner_id":"alice.testnet","price":"150"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not listed")]
+    fn update_price_not_listed() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.update_price("frog".to_owned(), U128(150));
+    }
+
+    #[test]
+    fn delist_success() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 1));
+
+        contract.delist("cat".to_owned());
+
+        assert!(contract.get_listing("cat".to_owned()).is_none());
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_delisted","data":[{"token_id":"cat","owner_id":"alice.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Predecessor must be meme owner")]
+    fn delist_not_owner() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.delist("cat".to_owned());
+    }
+
+    #[test]
+    fn buy_meme_success() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("carol.testnet", 100 + LISTING_DEPOSIT));
+
+        contract.buy_meme("cat".to_owned());
+
+        let token = contract.nft_token("cat".to_owned()).unwrap();
+        assert_eq!(token.owner_id, "carol.testnet".to_owned());
+        assert!(token.approved_account_ids.is_empty());
+        assert!(contract.get_listing("cat".to_owned()).is_none());
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"old_owner_id":"alice.testnet","new_owner_id":"carol.testnet","token_ids":["cat"]}]}"#,
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_sold","data":[{"token_id":"cat","seller_id":"alice.testnet","buyer_id":"carol.testnet","price":"100"}]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enought deposit attached. You need at least: 100 yoctoNEAR")]
+    fn buy_meme_not_enough_deposit() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("carol.testnet", 99));
+
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "or deposit it with storage_deposit to cover storage")]
+    fn buy_meme_without_owner_index_storage() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("carol.testnet", 100));
+
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    fn buy_meme_pays_owner_index_storage_from_storage_balance() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("carol.testnet", LISTING_DEPOSIT));
+        contract.storage_deposit(None, None);
+        let before = contract
+            .storage_balance_of("carol.testnet".to_owned())
+            .unwrap()
+            .total;
+        testing_env!(get_context("carol.testnet", 100));
+
+        contract.buy_meme("cat".to_owned());
+
+        assert!(
+            contract
+                .storage_balance_of("carol.testnet".to_owned())
+                .unwrap()
+                .total
+                .0
+                < before.0
+        );
+        assert!(contract
+            .memes_per_owner
+            .get(&"carol.testnet".to_owned())
+            .unwrap()
+            .contains(&"cat".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot buy own meme")]
+    fn buy_own_meme() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 100));
+
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not listed")]
+    fn buy_meme_listed_by_previous_owner() {
+        let mut contract = listed_contract();
+        let mut token = contract.memes_by_id.get(&"cat".to_owned()).unwrap();
+        token.owner_id = "bob.testnet".to_owned();
+        contract.memes_by_id.insert(&"cat".to_owned(), &token);
+        assert!(contract.get_listing("cat".to_owned()).is_none());
+        testing_env!(get_context("carol.testnet", 100));
+
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    fn buy_meme_refunds_listing_storage_to_seller() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", LISTING_DEPOSIT));
+        contract.storage_deposit(None, None);
+        let balance = |contract: &HusyContract| {
+            contract
+                .storage_balance_of("alice.testnet".to_owned())
+                .unwrap()
+                .total
+                .0
+        };
+        let before = balance(&contract);
+        testing_env!(get_context("carol.testnet", 100 + LISTING_DEPOSIT));
+
+        contract.buy_meme("cat".to_owned());
+
+        assert!(balance(&contract) > before);
+    }
+
+    #[test]
+    fn reverted_transfer_call_removes_receiver_listing() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+        contract.nft_transfer_call(
+            "market.testnet".to_owned(),
+            "frog".to_owned(),
+            None,
+            None,
+            "".to_owned(),
+        );
+        testing_env!(get_context("market.testnet", LISTING_DEPOSIT));
+        contract.list_meme("frog".to_owned(), U128(1));
+
+        testing_env!(
+            get_context("husy.testnet", 0),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            vec![PromiseResult::Successful(b"true".to_vec())]
+        );
+        contract.nft_resolve_transfer(
+            "bob.testnet".to_owned(),
+            "market.testnet".to_owned(),
+            "frog".to_owned(),
+            Default::default(),
+        );
+
+        assert_eq!(
+            contract.nft_token("frog".to_owned()).unwrap().owner_id,
+            "bob.testnet".to_owned()
+        );
+        assert!(contract.listings.get(&"frog".to_owned()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not listed")]
+    fn buy_meme_not_listed() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("carol.testnet", 100));
+
+        contract.buy_meme("frog".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn buy_meme_when_paused() {
+        let mut contract = listed_contract();
+        contract.paused = true;
+        testing_env!(get_context("carol.testnet", 100));
+
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    fn transfer_clears_listing() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 1));
+
+        contract.nft_transfer("carol.testnet".to_owned(), "cat".to_owned(), None, None);
+
+        assert!(contract.get_listing("cat".to_owned()).is_none());
+    }
+
+    #[test]
+    fn burn_clears_listing() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 1));
+
+        contract.nft_burn("cat".to_owned());
+
+        assert!(contract.get_listing("cat".to_owned()).is_none());
+        assert_eq!(contract.listings.len(), 2);
+    }
+
+    #[test]
+    fn get_listings_with_filters() {
+        let contract = listed_contract();
+
+        assert_eq!(
+            listed_ids(contract.get_listings(None, None, None, None)),
+            vec!["cat".to_owned(), "dog".to_owned(), "kitten".to_owned()]
+        );
+        assert_eq!(
+            listed_ids(contract.get_listings(Some(1), Some(1), None, None)),
+            vec!["dog".to_owned()]
+        );
+        assert_eq!(
+            listed_ids(contract.get_listings(None, None, Some("cats".to_owned()), None)),
+            vec!["cat".to_owned(), "kitten".to_owned()]
+        );
+        assert_eq!(
+            listed_ids(contract.get_listings(None, None, None, Some("bob.testnet".to_owned()))),
+            vec!["kitten".to_owned()]
+        );
+        assert_eq!(
+            listed_ids(contract.get_listings(
+                None,
+                None,
+                Some("cats".to_owned()),
+                Some("alice.testnet".to_owned())
+            )),
+            vec!["cat".to_owned()]
+        );
+        assert!(contract
+            .get_listings(None, None, None, Some("carol.testnet".to_owned()))
+            .is_empty());
+    }
+
+    #[test]
+    fn get_listings_of_category_reads_page_from_index() {
+        let mut contract = listed_contract();
+        testing_env!(get_context("alice.testnet", 1));
+        contract.delist("cat".to_owned());
+
+        assert_eq!(
+            listed_ids(contract.get_listings(None, None, Some("cats".to_owned()), None)),
+            vec!["kitten".to_owned()]
+        );
+        assert_eq!(
+            listed_ids(contract.get_listings(Some(1), Some(1), Some("cats".to_owned()), None)),
+            Vec::<MemeTokenId>::new()
+        );
+        assert!(contract
+            .get_listings(None, None, Some("frogs".to_owned()), None)
+            .is_empty());
+
+        testing_env!(get_context("bob.testnet", 1));
+        contract.delist("kitten".to_owned());
+        assert!(contract
+            .listings_per_category
+            .get(&"cats".to_owned())
+            .is_none());
+    }
+}
@@ -1,26 +1,29 @@
-use near_sdk::{env, near_bindgen, AccountId};
+use near_sdk::{env, near_bindgen, AccountId, Promise};
 
 use crate::{
     contract::MemeInteraction,
+    events::{HusyEvent, LikesGroupSwappedLog, MemeLikedLog, MemePromotedToMainLog},
     models::{
         husy::*,
         meme::{MemeTokenId, MemeTokenView},
+        meme_like::{MemeLikeView, MAX_LIKERS_REMOVED_PER_CALL},
     },
     utils::likes_helpers::{count_new_likes_state, try_move_to_main},
 };
 
-const YOCTO_NEAR_PER_LIKE: u128 = 50_000_000_000_000_000_000_000;
-
 #[near_bindgen]
 impl MemeInteraction for HusyContract {
     fn yocto_near_price_for_like(&self) -> u128 {
-        YOCTO_NEAR_PER_LIKE
+        self.likes_config.get().unwrap().yocto_near_per_like.0
     }
 
     #[payable]
     fn like_meme(&mut self, meme_id: MemeTokenId, likes: u64) {
+        self.assert_not_paused();
+        assert!(likes > 0, "Likes amount must be greater than 0");
         let attached = env::attached_deposit();
-        let needed = YOCTO_NEAR_PER_LIKE * likes as u128;
+        let likes_config = self.likes_config.get().unwrap();
+        let needed = likes_config.yocto_near_per_like.0 * likes as u128;
         assert!(
             attached >= needed,
             "Not enought deposit attached. You need at least: {} yoctoNEAR",
@@ -38,10 +41,55 @@ impl MemeInteraction for HusyContract {
         let mut global_likes_data = self.global_likes_data.get().unwrap();
 
         count_new_likes_state(&mut meme_additional_data, &mut global_likes_data, likes);
-        try_move_to_main(&mut meme_additional_data, &global_likes_data);
-        global_likes_data.try_switching_mode();
+        let promoted = !self.is_meme_hidden(&meme_id)
+            && try_move_to_main(&mut meme_additional_data, &global_likes_data, &likes_config);
+        let swapped = global_likes_data.try_switching_mode(&likes_config);
+
+        self.meme_additional_data_by_id
+            .insert(&meme_id, &meme_additional_data);
+        if promoted {
+            self.add_meme_to_main_feeds(&meme_id, &meme_additional_data.category);
+        }
+        self.global_likes_data.set(&global_likes_data);
+        let initial_storage_usage = env::storage_usage();
+        self.record_like(&predecessor_account_id, &meme_id, likes);
+        self.add_trending_likes(&meme_id, likes);
+        let refund = self.pay_for_storage(
+            &predecessor_account_id,
+            initial_storage_usage,
+            attached - needed,
+        );
+        self.prune_trending_buckets();
+
+        HusyEvent::MemeLiked(vec![MemeLikedLog {
+            meme_id: meme_id.clone(),
+            account_id: predecessor_account_id.clone(),
+            likes,
+            total_likes: meme_additional_data.likes,
+        }])
+        .emit();
+        if promoted {
+            HusyEvent::MemePromotedToMain(vec![MemePromotedToMainLog {
+                meme_id: meme_id.clone(),
+                likes: meme_additional_data.likes,
+            }])
+            .emit();
+        }
+        if swapped {
+            HusyEvent::LikesGroupSwapped(vec![LikesGroupSwappedLog {
+                swapped_at: global_likes_data.last_group_swap_timestamp,
+            }])
+            .emit();
+        }
+
+        self.distribute_like_payment(&meme_id, needed);
+        if refund > 0 {
+            Promise::new(predecessor_account_id).transfer(refund);
+        }
     }
 
+    /// Unfiltered feed leaves hidden memes out of requested page,
+    /// so page can be shorter than `limit` even if more memes follow
     fn get_memes(
         &self,
         from_index: Option<u128>,
@@ -50,51 +98,124 @@ impl MemeInteraction for HusyContract {
         owner: Option<AccountId>,
         main_page_only: bool,
     ) -> Vec<MemeTokenView> {
-        self.meme_additional_data_by_id
+        self.get_feed_meme_ids(
+            from_index.unwrap_or(0) as u64,
+            limit,
+            &category,
+            &owner,
+            main_page_only,
+        )
+        .into_iter()
+        .filter_map(|meme_id| self.get_meme_view(meme_id, None))
+        .collect()
+    }
+
+    /// Memes stay listed after creator sells them, hidden ones are skipped
+    fn get_memes_by_creator(
+        &self,
+        creator_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView> {
+        let memes = match self.memes_per_creator.get(&creator_id) {
+            Some(memes) => memes,
+            None => return vec![],
+        };
+
+        memes
+            .iter()
+            .filter(|id| !self.is_meme_hidden(id))
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(memes.len()) as usize)
+            .filter_map(|id| self.get_meme_view(id, None))
+            .collect()
+    }
+
+    fn get_account_like(
+        &self,
+        account_id: AccountId,
+        meme_id: MemeTokenId,
+    ) -> Option<MemeLikeView> {
+        self.get_like_view(account_id, meme_id)
+    }
+
+    fn get_likes_by_account(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeLikeView> {
+        let account_likes = match self.likes_per_account.get(&account_id) {
+            Some(account_likes) => account_likes,
+            None => return vec![],
+        };
+
+        account_likes
             .iter()
-            .filter(|(_key, value)| !main_page_only || value.showed_on_main)
-            .filter(|(_key, value)| match (&value.category, &category) {
-                (Some(meme_category), Some(category)) => meme_category == category,
-                (_, None) => true,
-                _ => false,
-            })
-            .filter_map(|(key, value)| {
-                let token = self.memes_by_id.get(&key).unwrap();
-                match &owner {
-                    Some(owner) if owner != &token.owner_id => None,
-                    _ => Some((key, value, token)),
-                }
-            })
             .skip(from_index.unwrap_or(0) as usize)
-            .take(limit.unwrap_or(self.meme_additional_data_by_id.len()) as usize)
-            .map(|(key, value, token)| {
-                let metadata = self.meme_metadata_by_id.get(&key).unwrap();
-                MemeTokenView {
-                    token_id: key,
-                    owner_id: token.owner_id,
-                    approved_account_ids: token.approved_account_ids,
-                    metadata,
-                    royalty: token.royalty,
-                    likes: value.likes,
-                    showed_on_main: value.showed_on_main,
-                    category: value.category,
-                }
+            .take(limit.unwrap_or(account_likes.len()) as usize)
+            .map(|(meme_id, like)| MemeLikeView {
+                meme_id,
+                account_id: account_id.clone(),
+                likes: like.likes,
+                first_liked_at: like.first_liked_at,
+                last_liked_at: like.last_liked_at,
             })
             .collect()
     }
+
+    fn get_likers_of_meme(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeLikeView> {
+        let likers = match self.likers_per_meme.get(&meme_id) {
+            Some(likers) => likers,
+            None => return vec![],
+        };
+
+        likers
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(likers.len()) as usize)
+            .filter_map(|account_id| self.get_like_view(account_id, meme_id.clone()))
+            .collect()
+    }
+
+    /// Burn leaves likes ledger in place, anyone can remove it in batches
+    /// afterwards. Returns count of likers left
+    fn remove_burned_meme_likes(&mut self, meme_id: MemeTokenId, limit: Option<u64>) -> u64 {
+        assert!(
+            !self.memes_by_id.contains_key(&meme_id),
+            "Meme is not burned"
+        );
+        let limit = limit
+            .unwrap_or(MAX_LIKERS_REMOVED_PER_CALL)
+            .min(MAX_LIKERS_REMOVED_PER_CALL);
+
+        self.remove_meme_likes(&meme_id, limit)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use near_sdk::MockedBlockchain;
-    use near_sdk::{test_utils::VMContextBuilder, testing_env, AccountId, VMContext};
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, AccountId, VMContext,
+    };
 
-    use crate::contract::ContractInit;
+    use crate::contract::{ContractInit, MintNFT, NFTBurn, NFTTokenCore, StorageManagement};
+    use crate::models::likes_config::DEFAULT_YOCTO_NEAR_PER_LIKE as YOCTO_NEAR_PER_LIKE;
     use crate::models::meme::MemeToken;
     use crate::models::meme_additional_data::MemeAdditionalData;
+    use crate::models::role::Role;
 
     use super::*;
 
+    const LEDGER_STORAGE_DEPOSIT: u128 = 20_000_000_000_000_000_000_000;
+
     fn get_context(predecessor_account_id: AccountId, attached: u128) -> VMContext {
         VMContextBuilder::new()
             .predecessor_account_id(predecessor_account_id.try_into().unwrap())
@@ -125,6 +246,8 @@ mod test {
                     ..Default::default()
                 },
             );
+            contract.add_meme_to_owner(&meme.owner_id, &meme.token_id);
+            contract.add_meme_to_feeds(&meme.token_id);
         }
     }
 
@@ -148,6 +271,225 @@ mod test {
         contract.like_meme(meme_id, 1);
     }
 
+    #[test]
+    fn like_meme_persists_state_and_ledger() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let liker_id = "liker.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        let mut context = get_context(owner_id.clone(), 0);
+        context.block_timestamp = 10;
+        testing_env!(context);
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                ..Default::default()
+            }],
+        );
+
+        let mut context = get_context(
+            liker_id.clone(),
+            YOCTO_NEAR_PER_LIKE * 3 + LEDGER_STORAGE_DEPOSIT,
+        );
+        context.block_timestamp = 20;
+        testing_env!(context);
+        contract.like_meme(meme_id.clone(), 3);
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_liked","data":[{"meme_id":"meme.testnet","account_id":"liker.testnet","likes":3,"total_likes":3}]}"#,
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_promoted_to_main","data":[{"meme_id":"meme.testnet","likes":3}]}"#,
+            ]
+        );
+        let meme_additional_data = contract.meme_additional_data_by_id.get(&meme_id).unwrap();
+        assert_eq!(meme_additional_data.likes, 3);
+        assert!(meme_additional_data.showed_on_main);
+        let global_likes_data = contract.global_likes_data.get().unwrap();
+        assert_eq!(global_likes_data.first_group_sum, 3);
+        assert_eq!(global_likes_data.first_group_liked_memes, 1);
+
+        let expected_like = MemeLikeView {
+            meme_id: meme_id.clone(),
+            account_id: liker_id.clone(),
+            likes: 3,
+            first_liked_at: 20,
+            last_liked_at: 20,
+        };
+        assert_eq!(
+            contract.get_account_like(liker_id.clone(), meme_id.clone()),
+            Some(expected_like.clone())
+        );
+        assert_eq!(
+            contract.get_likes_by_account(liker_id, None, None),
+            vec![expected_like.clone()]
+        );
+        assert_eq!(
+            contract.get_likers_of_meme(meme_id, None, None),
+            vec![expected_like]
+        );
+    }
+
+    #[test]
+    fn like_meme_swaps_likes_groups() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                showed_on_main: true,
+                ..Default::default()
+            }],
+        );
+
+        let swapped_at = contract.likes_config.get().unwrap().min_time_to_switch_mode + 1;
+        let mut context = get_context(
+            "liker.testnet".to_owned(),
+            YOCTO_NEAR_PER_LIKE + LEDGER_STORAGE_DEPOSIT,
+        );
+        context.block_timestamp = swapped_at;
+        testing_env!(context);
+        contract.like_meme(meme_id, 1);
+
+        assert_eq!(
+            get_logs().last().unwrap(),
+            &format!(
+                r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"likes_group_swapped","data":[{{"swapped_at":{}}}]}}"#,
+                swapped_at
+            )
+        );
+    }
+
+    #[test]
+    fn like_meme_splits_payment() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                ..Default::default()
+            }],
+        );
+
+        testing_env!(get_context(
+            "liker.testnet".to_owned(),
+            YOCTO_NEAR_PER_LIKE * 2 + 1 + LEDGER_STORAGE_DEPOSIT
+        ));
+        contract.like_meme(meme_id, 2);
+
+        let split = contract.like_revenue_split.get().unwrap();
+        let paid = YOCTO_NEAR_PER_LIKE * 2;
+        assert_eq!(
+            contract.reward_pool_balance,
+            paid * split.reward_pool_share as u128 / 10_000
+        );
+        assert_eq!(
+            contract.treasury_balance,
+            paid * split.treasury_share as u128 / 10_000
+        );
+    }
+
+    #[test]
+    fn like_meme_accumulates_likes_of_same_account() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let liker_id = "liker.testnet".to_owned();
+        let other_liker_id = "other_liker.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                ..Default::default()
+            }],
+        );
+
+        let mut context = get_context(
+            liker_id.clone(),
+            YOCTO_NEAR_PER_LIKE + LEDGER_STORAGE_DEPOSIT,
+        );
+        context.block_timestamp = 5;
+        testing_env!(context);
+        contract.like_meme(meme_id.clone(), 1);
+        let mut context = get_context(
+            liker_id.clone(),
+            YOCTO_NEAR_PER_LIKE + LEDGER_STORAGE_DEPOSIT,
+        );
+        context.block_timestamp = 15;
+        testing_env!(context);
+        contract.like_meme(meme_id.clone(), 1);
+        testing_env!(get_context(
+            other_liker_id.clone(),
+            YOCTO_NEAR_PER_LIKE + LEDGER_STORAGE_DEPOSIT
+        ));
+        contract.like_meme(meme_id.clone(), 1);
+
+        assert_eq!(
+            contract.get_account_like(liker_id.clone(), meme_id.clone()),
+            Some(MemeLikeView {
+                meme_id: meme_id.clone(),
+                account_id: liker_id,
+                likes: 2,
+                first_liked_at: 5,
+                last_liked_at: 15,
+            })
+        );
+        assert_eq!(
+            contract
+                .get_likers_of_meme(meme_id.clone(), None, None)
+                .len(),
+            2
+        );
+        assert_eq!(
+            contract.get_likers_of_meme(meme_id.clone(), Some(1), Some(1))[0].account_id,
+            other_liker_id
+        );
+        assert_eq!(
+            contract
+                .meme_additional_data_by_id
+                .get(&meme_id)
+                .unwrap()
+                .likes,
+            3
+        );
+    }
+
+    #[test]
+    fn get_likes_by_account_without_likes() {
+        testing_env!(get_context("owner_id.testnet".to_owned(), 0));
+        let contract = HusyContract::new_default("owner_id.testnet".to_owned());
+
+        assert!(contract
+            .get_likes_by_account("nobody.testnet".to_owned(), None, None)
+            .is_empty());
+        assert!(contract
+            .get_likers_of_meme("meme.testnet".to_owned(), None, None)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn like_meme_when_paused() {
+        let owner_id = "owner_id.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), YOCTO_NEAR_PER_LIKE));
+        let mut contract = HusyContract::new_default(owner_id);
+        contract.paused = true;
+
+        contract.like_meme("some_meme.testnet".to_owned(), 1);
+    }
+
     #[test]
     #[should_panic]
     fn like_meme_not_enought_attached_deposit() {
@@ -159,6 +501,156 @@ mod test {
         contract.like_meme("some_meme.testnet".to_owned(), 50);
     }
 
+    #[test]
+    fn like_meme_pays_ledger_storage_from_storage_balance() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let liker_id = "liker.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                ..Default::default()
+            }],
+        );
+        testing_env!(get_context(liker_id.clone(), LEDGER_STORAGE_DEPOSIT));
+        contract.storage_deposit(None, None);
+
+        testing_env!(get_context(liker_id.clone(), YOCTO_NEAR_PER_LIKE));
+        contract.like_meme(meme_id.clone(), 1);
+
+        assert!(contract
+            .get_account_like(liker_id.clone(), meme_id)
+            .is_some());
+        assert!(contract.storage_balance_of(liker_id).unwrap().total.0 < LEDGER_STORAGE_DEPOSIT);
+    }
+
+    fn liked_burned_contract(likers: u64) -> HusyContract {
+        let owner_id = "owner_id.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: "meme.testnet".to_owned(),
+                owner_id: owner_id.clone(),
+                ..Default::default()
+            }],
+        );
+        for index in 0..likers {
+            let liker_id = format!("liker{}.testnet", index);
+            testing_env!(get_context(liker_id.clone(), LEDGER_STORAGE_DEPOSIT));
+            contract.storage_deposit(None, None);
+            testing_env!(get_context(liker_id, YOCTO_NEAR_PER_LIKE));
+            contract.like_meme("meme.testnet".to_owned(), 1);
+        }
+        testing_env!(get_context(owner_id, 1));
+        contract.nft_burn("meme.testnet".to_owned());
+
+        contract
+    }
+
+    #[test]
+    fn remove_burned_meme_likes_refunds_ledger_storage_to_liker() {
+        let mut contract = liked_burned_contract(1);
+        let liker_id = "liker0.testnet".to_owned();
+        let storage_balance = contract.storage_balance_of(liker_id.clone()).unwrap();
+        testing_env!(get_context("anyone.testnet".to_owned(), 0));
+
+        assert_eq!(
+            contract.remove_burned_meme_likes("meme.testnet".to_owned(), None),
+            0
+        );
+
+        assert!(contract
+            .get_account_like(liker_id.clone(), "meme.testnet".to_owned())
+            .is_none());
+        // Trending entry paid by liker stays until its bucket is pruned
+        let refunded_balance = contract.storage_balance_of(liker_id).unwrap().total.0;
+        assert!(storage_balance.total.0 < refunded_balance);
+        assert!(refunded_balance < LEDGER_STORAGE_DEPOSIT);
+    }
+
+    #[test]
+    fn remove_burned_meme_likes_in_batches() {
+        let mut contract = liked_burned_contract(3);
+        testing_env!(get_context("anyone.testnet".to_owned(), 0));
+
+        assert_eq!(
+            contract.remove_burned_meme_likes("meme.testnet".to_owned(), Some(2)),
+            1
+        );
+        assert_eq!(
+            contract
+                .get_likers_of_meme("meme.testnet".to_owned(), None, None)
+                .len(),
+            1
+        );
+        assert_eq!(
+            contract.remove_burned_meme_likes("meme.testnet".to_owned(), Some(2)),
+            0
+        );
+        assert!(contract
+            .likers_per_meme
+            .get(&"meme.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Likes of burned meme must be removed first")]
+    fn nft_mint_burned_meme_with_likes() {
+        let mut contract = liked_burned_contract(1);
+        testing_env!(get_context("owner_id.testnet".to_owned(), 0));
+
+        contract.internal_mint(
+            &"meme.testnet".to_owned(),
+            Some(&Default::default()),
+            "owner_id.testnet".to_owned(),
+            Default::default(),
+            None,
+            "owner_id.testnet".to_owned(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not burned")]
+    fn remove_burned_meme_likes_of_existing_meme() {
+        let mut contract = liked_burned_contract(0);
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: "meme.testnet".to_owned(),
+                owner_id: "owner_id.testnet".to_owned(),
+                ..Default::default()
+            }],
+        );
+
+        contract.remove_burned_meme_likes("meme.testnet".to_owned(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "or deposit it with storage_deposit to cover storage")]
+    fn like_meme_without_ledger_storage_deposit() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                ..Default::default()
+            }],
+        );
+
+        testing_env!(get_context("liker.testnet".to_owned(), YOCTO_NEAR_PER_LIKE));
+        contract.like_meme(meme_id, 1);
+    }
+
     #[test]
     fn yocto_near_price_for_like_test() {
         let owner_id = "owner_id.testnet".to_owned();
@@ -302,4 +794,259 @@ mod test {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn get_memes_pages_over_feed_with_hidden_memes() {
+        let owner_id = "owner_id.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        let memes: Vec<MemeTokenView> = ["cat", "dog", "frog"]
+            .iter()
+            .map(|meme_id| MemeTokenView {
+                token_id: meme_id.to_string(),
+                owner_id: owner_id.clone(),
+                category: Some("animals".to_owned()),
+                ..Default::default()
+            })
+            .collect();
+        feed_contract(&mut contract, &memes);
+        contract.remove_meme_from_feeds(&"cat".to_owned());
+        contract.hidden_memes.insert(&"cat".to_owned());
+        let ids = |views: Vec<MemeTokenView>| -> Vec<MemeTokenId> {
+            views.into_iter().map(|view| view.token_id).collect()
+        };
+
+        assert!(contract
+            .get_memes(None, Some(1), None, None, false)
+            .is_empty());
+        assert_eq!(
+            ids(contract.get_memes(Some(1), Some(2), None, None, false)),
+            vec!["dog".to_owned(), "frog".to_owned()]
+        );
+        // Owner feeds never contain hidden memes, so their pages are full
+        assert_eq!(
+            contract
+                .get_memes(
+                    None,
+                    Some(2),
+                    Some("animals".to_owned()),
+                    Some(owner_id),
+                    false
+                )
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn get_memes_by_owner_follows_transfer() {
+        let owner_id = "owner_id.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: "cat".to_owned(),
+                owner_id: owner_id.clone(),
+                category: Some("cats".to_owned()),
+                showed_on_main: true,
+                ..Default::default()
+            }],
+        );
+
+        testing_env!(get_context(owner_id.clone(), 1));
+        contract.nft_transfer("buyer.testnet".to_owned(), "cat".to_owned(), None, None);
+
+        assert!(contract
+            .get_memes(None, None, Some("cats".to_owned()), Some(owner_id), true)
+            .is_empty());
+        assert_eq!(
+            contract
+                .get_memes(
+                    None,
+                    None,
+                    Some("cats".to_owned()),
+                    Some("buyer.testnet".to_owned()),
+                    true
+                )
+                .len(),
+            1
+        );
+        assert_eq!(
+            contract
+                .get_memes(None, None, None, Some("buyer.testnet".to_owned()), false)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn get_memes_from_single_index() {
+        let owner_id = "owner_id.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        let memes = vec![
+            MemeTokenView {
+                token_id: "cat_main.testnet".to_owned(),
+                owner_id: owner_id.clone(),
+                category: Some("cats".to_owned()),
+                showed_on_main: true,
+                ..Default::default()
+            },
+            MemeTokenView {
+                token_id: "cat.testnet".to_owned(),
+                owner_id: "other.testnet".to_owned(),
+                category: Some("cats".to_owned()),
+                ..Default::default()
+            },
+            MemeTokenView {
+                token_id: "dog_main.testnet".to_owned(),
+                owner_id: "other.testnet".to_owned(),
+                category: Some("dogs".to_owned()),
+                showed_on_main: true,
+                ..Default::default()
+            },
+            MemeTokenView {
+                token_id: "no_category.testnet".to_owned(),
+                owner_id: owner_id.clone(),
+                ..Default::default()
+            },
+        ];
+        feed_contract(&mut contract, &memes);
+        let ids = |views: Vec<MemeTokenView>| -> Vec<MemeTokenId> {
+            views.into_iter().map(|view| view.token_id).collect()
+        };
+
+        assert_eq!(
+            ids(contract.get_memes(None, None, Some("cats".to_owned()), None, false)),
+            vec!["cat_main.testnet".to_owned(), "cat.testnet".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_memes(Some(1), Some(5), None, None, true)),
+            vec!["dog_main.testnet".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_memes(None, Some(1), None, Some(owner_id), false)),
+            vec!["cat_main.testnet".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_memes(Some(3), None, None, None, false)),
+            vec!["no_category.testnet".to_owned()]
+        );
+        assert!(contract
+            .get_memes(None, None, Some("frogs".to_owned()), None, false)
+            .is_empty());
+        assert!(contract
+            .get_memes(None, None, None, Some("nobody.testnet".to_owned()), false)
+            .is_empty());
+    }
+
+    #[test]
+    fn like_meme_adds_promoted_meme_to_main_feed() {
+        let owner_id = "owner_id.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        testing_env!(get_context(owner_id.clone(), 0));
+        let mut contract = HusyContract::new_default(owner_id.clone());
+        feed_contract(
+            &mut contract,
+            &[MemeTokenView {
+                token_id: meme_id.clone(),
+                owner_id,
+                category: Some("cats".to_owned()),
+                ..Default::default()
+            }],
+        );
+        assert!(contract.get_memes(None, None, None, None, true).is_empty());
+
+        testing_env!(get_context(
+            "liker.testnet".to_owned(),
+            YOCTO_NEAR_PER_LIKE * 3 + LEDGER_STORAGE_DEPOSIT
+        ));
+        contract.like_meme(meme_id.clone(), 3);
+
+        let main_page = contract.get_memes(None, None, Some("cats".to_owned()), None, true);
+        assert_eq!(main_page.len(), 1);
+        assert_eq!(main_page[0].token_id, meme_id);
+    }
+
+    #[test]
+    fn get_memes_by_creator_after_transfer_and_burn() {
+        let creator_id = "creator.testnet".to_owned();
+        testing_env!(get_context("owner.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&creator_id, &vec![Role::Minter]);
+        for meme_id in ["cat", "dog", "frog"] {
+            testing_env!(VMContextBuilder::new()
+                .predecessor_account_id(creator_id.clone().try_into().unwrap())
+                .attached_deposit(LEDGER_STORAGE_DEPOSIT * 10)
+                .block_timestamp(42)
+                .build());
+            contract.nft_mint(
+                meme_id.to_owned(),
+                Default::default(),
+                creator_id.clone(),
+                None,
+                None,
+                None,
+            );
+        }
+
+        testing_env!(get_context(creator_id.clone(), 1));
+        contract.nft_transfer("buyer.testnet".to_owned(), "cat".to_owned(), None, None);
+        testing_env!(get_context(creator_id.clone(), 1));
+        contract.nft_burn("dog".to_owned());
+
+        let memes = contract.get_memes_by_creator(creator_id.clone(), None, None);
+        assert_eq!(
+            memes
+                .iter()
+                .map(|meme| (
+                    meme.token_id.as_str(),
+                    meme.owner_id.as_str(),
+                    meme.creator_id.as_str(),
+                    meme.minted_at
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("cat", "buyer.testnet", "creator.testnet", 42),
+                ("frog", "creator.testnet", "creator.testnet", 42),
+            ]
+        );
+        assert_eq!(
+            contract.get_memes_by_creator(creator_id, Some(1), Some(1))[0].token_id,
+            "frog".to_owned()
+        );
+        assert!(contract
+            .get_memes_by_creator("buyer.testnet".to_owned(), None, None)
+            .is_empty());
+    }
+
+    #[test]
+    fn get_memes_by_creator_pages_over_visible_memes() {
+        let creator_id = "creator.testnet".to_owned();
+        testing_env!(get_context(creator_id.clone(), LEDGER_STORAGE_DEPOSIT * 10));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&creator_id, &vec![Role::Minter]);
+        for meme_id in ["cat", "dog", "frog"] {
+            testing_env!(get_context(creator_id.clone(), LEDGER_STORAGE_DEPOSIT * 10));
+            contract.nft_mint(
+                meme_id.to_owned(),
+                Default::default(),
+                creator_id.clone(),
+                None,
+                None,
+                None,
+            );
+        }
+        contract.hidden_memes.insert(&"cat".to_owned());
+
+        let memes = contract.get_memes_by_creator(creator_id, Some(1), Some(1));
+
+        assert_eq!(memes.len(), 1);
+        assert_eq!(memes[0].token_id, "frog".to_owned());
+    }
 }
@@ -1,21 +1,74 @@
-use near_sdk::near_bindgen;
+use near_sdk::{json_types::Base64VecU8, near_bindgen};
 
 use crate::contract::NFTContractMetadata;
 use crate::models::husy::*;
 use crate::models::husy_metadata::HusyNFTContractMetadata;
+use crate::utils::metadata_validation::assert_valid_contract_metadata;
+
+impl HusyContract {
+    fn update_contract_metadata(&mut self, update: impl FnOnce(&mut HusyNFTContractMetadata)) {
+        self.assert_owner();
+        let mut metadata = self.metadata.get().expect("Failed to get metadata");
+        update(&mut metadata);
+        assert_valid_contract_metadata(&metadata);
+        self.metadata.set(&metadata);
+    }
+}
 
 #[near_bindgen]
 impl NFTContractMetadata for HusyContract {
     fn nft_metadata(&self) -> HusyNFTContractMetadata {
         self.metadata.get().expect("Failed to get metadata")
     }
+
+    fn set_contract_name(&mut self, name: String) {
+        assert!(!name.trim().is_empty(), "Name cannot be empty");
+        self.update_contract_metadata(|metadata| metadata.name = name);
+    }
+
+    fn set_contract_symbol(&mut self, symbol: String) {
+        assert!(!symbol.trim().is_empty(), "Symbol cannot be empty");
+        self.update_contract_metadata(|metadata| metadata.symbol = symbol);
+    }
+
+    fn set_contract_icon(&mut self, icon: Option<String>) {
+        self.update_contract_metadata(|metadata| metadata.icon = icon);
+    }
+
+    /// Memes minted with relative media keep pointing to the new base URI
+    fn set_base_uri(&mut self, base_uri: Option<String>) {
+        self.update_contract_metadata(|metadata| metadata.base_uri = base_uri);
+    }
+
+    fn set_contract_reference(
+        &mut self,
+        reference: Option<String>,
+        reference_hash: Option<Base64VecU8>,
+    ) {
+        self.update_contract_metadata(|metadata| {
+            metadata.reference = reference;
+            metadata.reference_hash = reference_hash;
+        });
+    }
+
+    fn get_resolve_relative_uris(&self) -> bool {
+        self.resolve_relative_uris
+    }
+
+    /// When enabled, meme views return relative media and reference
+    /// joined with `base_uri`, stored metadata stays unchanged
+    fn set_resolve_relative_uris(&mut self, resolve_relative_uris: bool) {
+        self.assert_owner();
+        self.resolve_relative_uris = resolve_relative_uris;
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use near_sdk::{testing_env, VMContext};
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
 
-    use crate::contract::ContractInit;
+    use crate::contract::{ContractInit, MintNFT, NFTTokenCore};
+    use crate::models::meme_metadata::MemeTokenMetadata;
     use near_sdk::MockedBlockchain;
 
     use super::*;
@@ -61,4 +114,146 @@ mod test {
 
         assert_eq!(result, metadata)
     }
+
+    #[test]
+    fn set_contract_metadata_fields() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.set_contract_name("Husy".to_owned());
+        contract.set_contract_symbol("HUSY".to_owned());
+        contract.set_contract_icon(Some("data:image/svg+xml,<svg></svg>".to_owned()));
+        contract.set_base_uri(Some("https://husy.io".to_owned()));
+        contract.set_contract_reference(
+            Some("ipfs://reference.json".to_owned()),
+            Some(Base64VecU8(vec![1; 32])),
+        );
+
+        assert_eq!(
+            contract.nft_metadata(),
+            HusyNFTContractMetadata {
+                spec: "nft-1.0.0".to_owned(),
+                name: "Husy".to_owned(),
+                symbol: "HUSY".to_owned(),
+                icon: Some("data:image/svg+xml,<svg></svg>".to_owned()),
+                base_uri: Some("https://husy.io".to_owned()),
+                reference: Some("ipfs://reference.json".to_owned()),
+                reference_hash: Some(Base64VecU8(vec![1; 32])),
+            }
+        );
+
+        contract.set_contract_icon(None);
+        assert_eq!(contract.nft_metadata().icon, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn set_contract_name_not_owner() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        testing_env!(get_context("bbb.testnet".to_owned(), 0));
+
+        contract.set_contract_name("Husy".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Symbol cannot be empty")]
+    fn set_contract_symbol_empty() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.set_contract_symbol(" ".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Icon must be a data URL")]
+    fn set_contract_icon_not_data_url() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.set_contract_icon(Some("https://husy.io/icon.png".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference hash is required when reference is set")]
+    fn set_contract_reference_without_hash() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.set_contract_reference(Some("ipfs://reference.json".to_owned()), None);
+    }
+
+    #[test]
+    fn meme_view_resolves_relative_uris() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        contract.set_base_uri(Some("https://husy.io/memes/".to_owned()));
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id("aaa.testnet".try_into().unwrap())
+            .attached_deposit(10u128.pow(23))
+            .build());
+        contract.nft_mint(
+            "cat".to_owned(),
+            MemeTokenMetadata {
+                media: Some("cat.png".to_owned()),
+                media_hash: Some(Base64VecU8(vec![1; 32])),
+                reference: Some("ar://cat".to_owned()),
+                reference_hash: Some(Base64VecU8(vec![1; 32])),
+                ..Default::default()
+            },
+            "aaa.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+        let media = |contract: &HusyContract| {
+            let metadata = contract.nft_token("cat".to_owned()).unwrap().metadata;
+            (metadata.media.unwrap(), metadata.reference.unwrap())
+        };
+        assert_eq!(
+            media(&contract),
+            ("cat.png".to_owned(), "ar://cat".to_owned())
+        );
+
+        contract.set_resolve_relative_uris(true);
+
+        assert!(contract.get_resolve_relative_uris());
+        assert_eq!(
+            media(&contract),
+            (
+                "https://husy.io/memes/cat.png".to_owned(),
+                "ar://cat".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn set_resolve_relative_uris_not_owner() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        testing_env!(get_context("bbb.testnet".to_owned(), 0));
+
+        contract.set_resolve_relative_uris(true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Spec must be a recognized nft-1.x version")]
+    fn new_with_unknown_spec() {
+        let context = get_context("aaa.testnet".to_owned(), 10000000);
+        testing_env!(context);
+
+        HusyContract::new(
+            "aaa.testnet".to_owned(),
+            HusyNFTContractMetadata {
+                spec: "nft-2.0.0".to_owned(),
+                name: "TestNFT".to_owned(),
+                symbol: "TEST".to_owned(),
+                icon: None,
+                base_uri: None,
+                reference: None,
+                reference_hash: None,
+            },
+        );
+    }
 }
@@ -0,0 +1,317 @@
+use near_sdk::{env, near_bindgen};
+
+use crate::{
+    contract::NFTMetadataUpdate,
+    events::{HusyEvent, MemeMetadataUpdatedLog},
+    models::{
+        husy::*,
+        meme::MemeTokenId,
+        metadata_update::{MemeMetadataPatch, MetadataRevision},
+    },
+    utils::metadata_update_helpers::{apply_metadata_patch, changes_media},
+};
+
+#[near_bindgen]
+impl NFTMetadataUpdate for HusyContract {
+    /// Only creator can update metadata and only while owning the meme.
+    /// Replaced metadata is kept in revision history paid by creator
+    #[payable]
+    fn nft_update_metadata(&mut self, token_id: MemeTokenId, patch: MemeMetadataPatch) {
+        self.assert_not_paused();
+        self.assert_not_frozen(&token_id);
+        let token = self.memes_by_id.get(&token_id).expect("Meme not found");
+        let creator_id = env::predecessor_account_id();
+        let additional_data = self.meme_additional_data_by_id.get(&token_id).unwrap();
+        assert!(
+            additional_data.creator_id == creator_id && token.owner_id == creator_id,
+            "Only creator owning the meme can update its metadata"
+        );
+        let metadata = self
+            .meme_metadata_by_id
+            .get(&token_id)
+            .expect("Edition metadata is shared by series and cannot be updated");
+        assert!(patch != MemeMetadataPatch::default(), "Nothing to update");
+        let media_changed = changes_media(&metadata, &patch);
+        assert!(
+            !media_changed || !self.is_media_locked(&token_id),
+            "Media cannot be changed after meme was liked or sold"
+        );
+
+        let updated_at = env::block_timestamp();
+        let updated_metadata = apply_metadata_patch(&metadata, patch, updated_at);
+        self.assert_valid_meme_metadata(&updated_metadata);
+        let initial_storage_usage = env::storage_usage();
+        self.meme_metadata_by_id
+            .insert(&token_id, &updated_metadata);
+        self.add_metadata_revision(
+            &token_id,
+            MetadataRevision {
+                metadata,
+                replaced_at: updated_at,
+            },
+        );
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::MemeMetadataUpdated(vec![MemeMetadataUpdatedLog {
+            token_id,
+            creator_id,
+            media_changed,
+            updated_at,
+        }])
+        .emit();
+    }
+
+    /// Revisions are ordered from oldest to newest
+    fn get_metadata_revisions(&self, token_id: MemeTokenId) -> Vec<MetadataRevision> {
+        self.metadata_revisions.get(&token_id).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::json_types::{Base64VecU8, U128};
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{
+        ContractInit, Editions, Marketplace, MemeInteraction, MintNFT, NFTTokenCore,
+    };
+    use crate::models::role::Role;
+    use crate::models::{
+        meme_metadata::MemeTokenMetadata, metadata_update::MAX_METADATA_REVISIONS,
+    };
+
+    use super::*;
+
+    const DEPOSIT: Balance = 100_000_000_000_000_000_000_000;
+    const PRICE: Balance = 1_000_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance, timestamp: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(timestamp)
+            .build()
+    }
+
+    fn minted_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0, 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"alice.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("alice.testnet", DEPOSIT, 0));
+        contract.nft_mint(
+            "cat".to_owned(),
+            MemeTokenMetadata {
+                title: Some("Cat".to_owned()),
+                media: Some("ipfs://cat.png".to_owned()),
+                media_hash: Some(Base64VecU8(vec![1; 32])),
+                ..Default::default()
+            },
+            "alice.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        contract
+    }
+
+    fn update(
+        contract: &mut HusyContract,
+        account_id: &str,
+        timestamp: u64,
+        patch: MemeMetadataPatch,
+    ) {
+        testing_env!(get_context(account_id, DEPOSIT, timestamp));
+        contract.nft_update_metadata("cat".to_owned(), patch);
+    }
+
+    fn title(title: &str) -> MemeMetadataPatch {
+        MemeMetadataPatch {
+            title: Some(title.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn media(media: &str) -> MemeMetadataPatch {
+        MemeMetadataPatch {
+            media: Some(media.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn sell(contract: &mut HusyContract, seller_id: &str, buyer_id: &str) {
+        testing_env!(get_context(seller_id, DEPOSIT, 0));
+        contract.list_meme("cat".to_owned(), U128(PRICE));
+        testing_env!(get_context(buyer_id, PRICE + DEPOSIT, 0));
+        contract.buy_meme("cat".to_owned());
+    }
+
+    #[test]
+    fn nft_update_metadata_by_creator() {
+        let mut contract = minted_contract();
+
+        update(&mut contract, "alice.testnet", 7, title("Angry cat"));
+
+        assert_eq!(
+            contract.nft_token("cat".to_owned()).unwrap().metadata,
+            MemeTokenMetadata {
+                title: Some("Angry cat".to_owned()),
+                media: Some("ipfs://cat.png".to_owned()),
+                media_hash: Some(Base64VecU8(vec![1; 32])),
+                updated_at: Some(7),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            contract.get_metadata_revisions("cat".to_owned()),
+            vec![MetadataRevision {
+                metadata: MemeTokenMetadata {
+                    title: Some("Cat".to_owned()),
+                    media: Some("ipfs://cat.png".to_owned()),
+                    media_hash: Some(Base64VecU8(vec![1; 32])),
+                    ..Default::default()
+                },
+                replaced_at: 7,
+            }]
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_metadata_updated","data":[{"token_id":"cat","creator_id":"alice.testnet","media_changed":false,"updated_at":7}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn nft_update_metadata_keeps_bounded_history() {
+        let mut contract = minted_contract();
+
+        for revision in 0..=MAX_METADATA_REVISIONS as u64 {
+            update(
+                &mut contract,
+                "alice.testnet",
+                revision,
+                title(&format!("Cat {}", revision)),
+            );
+        }
+
+        let revisions = contract.get_metadata_revisions("cat".to_owned());
+        assert_eq!(revisions.len(), MAX_METADATA_REVISIONS);
+        assert_eq!(revisions[0].metadata.title, Some("Cat 0".to_owned()));
+        assert_eq!(
+            revisions.last().unwrap().metadata.title,
+            Some(format!("Cat {}", MAX_METADATA_REVISIONS - 1))
+        );
+    }
+
+    #[test]
+    fn nft_update_metadata_media_before_like() {
+        let mut contract = minted_contract();
+
+        update(
+            &mut contract,
+            "alice.testnet",
+            1,
+            media("ipfs://angry-cat.png"),
+        );
+
+        assert_eq!(
+            contract.nft_token("cat".to_owned()).unwrap().metadata.media,
+            Some("ipfs://angry-cat.png".to_owned())
+        );
+        assert!(get_logs()[0].contains(r#""media_changed":true"#));
+    }
+
+    #[test]
+    fn nft_update_metadata_description_after_like() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("bob.testnet", DEPOSIT, 0));
+        contract.like_meme("cat".to_owned(), 1);
+
+        update(
+            &mut contract,
+            "alice.testnet",
+            1,
+            MemeMetadataPatch {
+                description: Some("Liked cat".to_owned()),
+                media: Some("ipfs://cat.png".to_owned()),
+                media_hash: Some(Base64VecU8(vec![1; 32])),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            contract
+                .nft_token("cat".to_owned())
+                .unwrap()
+                .metadata
+                .description,
+            Some("Liked cat".to_owned())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media cannot be changed after meme was liked or sold")]
+    fn nft_update_metadata_media_after_like() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("bob.testnet", DEPOSIT, 0));
+        contract.like_meme("cat".to_owned(), 1);
+
+        update(&mut contract, "alice.testnet", 1, media("ipfs://dog.png"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Media cannot be changed after meme was liked or sold")]
+    fn nft_update_metadata_media_after_sale() {
+        let mut contract = minted_contract();
+        sell(&mut contract, "alice.testnet", "bob.testnet");
+        sell(&mut contract, "bob.testnet", "alice.testnet");
+
+        update(&mut contract, "alice.testnet", 1, media("ipfs://dog.png"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only creator owning the meme can update its metadata")]
+    fn nft_update_metadata_by_new_owner() {
+        let mut contract = minted_contract();
+        sell(&mut contract, "alice.testnet", "bob.testnet");
+
+        update(&mut contract, "bob.testnet", 1, title("Bob's cat"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only creator owning the meme can update its metadata")]
+    fn nft_update_metadata_by_creator_after_sale() {
+        let mut contract = minted_contract();
+        sell(&mut contract, "alice.testnet", "bob.testnet");
+
+        update(&mut contract, "alice.testnet", 1, title("Still my cat"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Nothing to update")]
+    fn nft_update_metadata_empty_patch() {
+        let mut contract = minted_contract();
+
+        update(&mut contract, "alice.testnet", 1, Default::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "Edition metadata is shared by series and cannot be updated")]
+    fn nft_update_metadata_of_edition() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("alice.testnet", DEPOSIT, 0));
+        let series_id = contract.create_series(Default::default(), 2, None, None, None);
+        testing_env!(get_context("alice.testnet", DEPOSIT, 0));
+        let token_id = contract.mint_edition(series_id, "alice.testnet".to_owned());
+
+        testing_env!(get_context("alice.testnet", DEPOSIT, 1));
+        contract.nft_update_metadata(token_id, title("Edition"));
+    }
+}
@@ -1,10 +1,14 @@
-use near_sdk::{near_bindgen, AccountId};
+use near_sdk::{env, near_bindgen, AccountId};
 use std::collections::HashMap;
 
 use crate::{
     contract::MintNFT,
-    models::{husy::*, meme::MemeToken, meme::MemeTokenId, meme_metadata::MemeTokenMetadata},
-    utils::payment::with_refund,
+    events::{HusyEvent, MemeRemixedLog},
+    models::{
+        category::CategoryId, husy::*, meme::MemeTokenId, meme_metadata::MemeTokenMetadata,
+        provenance::PROVENANCE_STORAGE_RESERVE, role::Role,
+    },
+    utils::mint_helpers::{assert_valid_meme_id, assert_valid_royalty},
 };
 
 #[near_bindgen]
@@ -16,46 +20,60 @@ impl MintNFT for HusyContract {
         token_metadata: MemeTokenMetadata,
         receiver_id: AccountId,
         royalties: Option<HashMap<AccountId, u32>>,
+        category: Option<CategoryId>,
+        remix_of: Option<MemeTokenId>,
     ) {
-        with_refund(|| {
-            if let Some(royalties) = &royalties {
-                assert!(
-                    royalties.len() <= 5,
-                    "Cannot add more than 5 royalities account"
-                );
-                let sum: u32 = royalties.values().sum();
-                assert!(
-                    sum < 10_000,
-                    "Sum of royalities cannot be bigger than 10 000"
-                );
+        self.assert_not_paused();
+        self.assert_owner_or_role(Role::Minter);
+        assert_valid_meme_id(&token_id);
+        self.assert_valid_meme_metadata(&token_metadata);
+        let initial_storage_usage = env::storage_usage();
+
+        let mut royalty = royalties.unwrap_or_default();
+        // Creator of remixed meme gets royalty share on top of requested royalties
+        let remix_log = remix_of.map(|parent_id| {
+            let parent_creator_id = self
+                .meme_additional_data_by_id
+                .get(&parent_id)
+                .expect("Remixed meme not found")
+                .creator_id;
+            if self.remix_royalty > 0 {
+                *royalty.entry(parent_creator_id.clone()).or_insert(0) += self.remix_royalty;
             }
-            let meme = MemeToken {
-                owner_id: receiver_id,
-                royalty: royalties.unwrap_or_default(),
-                ..Default::default()
-            };
-            assert!(
-                self.memes_by_id.insert(&token_id, &meme).is_none(),
-                "Meme already exists"
-            );
+            MemeRemixedLog {
+                token_id: token_id.clone(),
+                remix_of: parent_id,
+                parent_creator_id,
+                royalty: self.remix_royalty,
+            }
+        });
+        assert_valid_royalty(&royalty);
 
-            self.meme_metadata_by_id.insert(&token_id, &token_metadata);
-            self.meme_additional_data_by_id
-                .insert(&token_id, &Default::default());
+        self.internal_mint(
+            &token_id,
+            Some(&token_metadata),
+            receiver_id,
+            royalty,
+            category,
+            env::predecessor_account_id(),
+        );
+        if let Some(remix_log) = remix_log {
+            self.add_remix(&remix_log.remix_of, &token_id);
+            HusyEvent::MemeRemixed(vec![remix_log]).emit();
+        }
 
-            (self.add_meme_to_owner(&meme.owner_id, &token_id), None)
-        });
+        self.pay_for_storage_and_refund(initial_storage_usage - PROVENANCE_STORAGE_RESERVE);
     }
 }
 
 #[cfg(test)]
 mod test {
-    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::test_utils::{get_logs, VMContextBuilder};
     use near_sdk::{testing_env, VMContext};
     use near_sdk::{Balance, MockedBlockchain};
 
-    use crate::contract::ContractInit;
-    use crate::models::meme_additional_data::MemeAdditionalData;
+    use crate::contract::{CategoryRegistry, ContractInit};
+    use crate::models::{meme::MemeToken, meme_additional_data::MemeAdditionalData};
 
     use super::*;
 
@@ -90,6 +108,8 @@ mod test {
                 ("account1.testnet".to_owned(), 9_999),
                 ("account2.testnet".to_owned(), 20),
             ])),
+            None,
+            None,
         );
     }
 
@@ -121,6 +141,27 @@ mod test {
                 ("account5.testnet".to_owned(), 20),
                 ("account6.testnet".to_owned(), 20),
             ])),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media hash is required when media is set")]
+    fn nft_mint_panic_media_without_hash() {
+        testing_env!(get_context("aaa.testnet".to_owned(), 0));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            MemeTokenMetadata {
+                media: Some("ipfs://meme.png".to_owned()),
+                ..Default::default()
+            },
+            "receiver.testnet".to_owned(),
+            None,
+            None,
+            None,
         );
     }
 
@@ -147,6 +188,8 @@ mod test {
                 ("account1.testnet".to_owned(), 10),
                 ("account2.testnet".to_owned(), 20),
             ])),
+            None,
+            None,
         );
 
         assert_eq!(
@@ -174,9 +217,123 @@ mod test {
         assert_eq!(
             contract.meme_additional_data_by_id.get(&token_id).unwrap(),
             MemeAdditionalData {
+                creator_id: "aaa.testnet".to_owned(),
                 ..Default::default()
             }
-        )
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[{"owner_id":"receiver.testnet","token_ids":["token.testnet"]}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn nft_mint_with_category() {
+        let context = get_context("aaa.testnet".to_owned(), 999999999999999999999999999);
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        contract.add_category("cats".to_owned(), "Cats".to_owned());
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            Default::default(),
+            "receiver.testnet".to_owned(),
+            None,
+            Some("cats".to_owned()),
+            None,
+        );
+
+        assert_eq!(
+            contract
+                .meme_additional_data_by_id
+                .get(&"token.testnet".to_owned())
+                .unwrap()
+                .category,
+            Some("cats".to_owned())
+        );
+        assert_eq!(
+            contract
+                .get_category("cats".to_owned())
+                .unwrap()
+                .memes_count,
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Category dogs does not exist")]
+    fn nft_mint_with_unknown_category() {
+        let context = get_context("aaa.testnet".to_owned(), 999999999999999999999999999);
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            Default::default(),
+            "receiver.testnet".to_owned(),
+            None,
+            Some("dogs".to_owned()),
+            None,
+        );
+    }
+
+    #[test]
+    fn nft_mint_by_minter() {
+        testing_env!(get_context("minter.testnet".to_owned(), 10u128.pow(24)));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"minter.testnet".to_owned(), &vec![Role::Minter]);
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            Default::default(),
+            "receiver.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        assert!(contract
+            .memes_by_id
+            .get(&"token.testnet".to_owned())
+            .is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner or account with Minter role")]
+    fn nft_mint_without_minter_role() {
+        testing_env!(get_context("someone.testnet".to_owned(), 10u128.pow(24)));
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            Default::default(),
+            "receiver.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn nft_mint_when_paused() {
+        let context = get_context("aaa.testnet".to_owned(), 999999999999999999999999999);
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        contract.paused = true;
+
+        contract.nft_mint(
+            "token.testnet".to_owned(),
+            Default::default(),
+            "receiver.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
     }
 
     #[test]
@@ -200,7 +357,9 @@ mod test {
             metadata.clone(),
             receiver_id.clone(),
             None,
+            None,
+            None,
         );
-        contract.nft_mint(token_id, metadata, receiver_id, None);
+        contract.nft_mint(token_id, metadata, receiver_id, None, None, None);
     }
 }
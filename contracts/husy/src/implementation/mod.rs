@@ -1,8 +1,26 @@
+pub mod admin;
 pub mod approval;
+pub mod auction;
+pub mod burn;
+pub mod category;
+pub mod comment;
 pub mod enumeration;
 pub mod init;
+pub mod likes_config;
+pub mod marketplace;
 pub mod meme_interaction;
 pub mod metadata;
+pub mod metadata_update;
 pub mod mint;
+pub mod moderation;
 pub mod nft_core;
+pub mod offer;
+pub mod provenance;
+pub mod remix;
+pub mod revenue;
 pub mod royality;
+pub mod series;
+pub mod storage_management;
+pub mod trending;
+pub mod upgrade;
+pub mod voucher;
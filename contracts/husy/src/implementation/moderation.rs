@@ -0,0 +1,548 @@
+use near_sdk::{borsh::BorshSerialize, collections::UnorderedMap, env, near_bindgen};
+
+use crate::{
+    contract::Moderation,
+    events::{HusyEvent, MemeReportedLog, ModerationDecisionLog},
+    models::{
+        husy::*,
+        meme::{MemeTokenId, MemeTokenView},
+        moderation::{
+            MemeModerationView, Report, ReportView, MAX_REPORTS_PER_MEME, REPORT_DEPOSIT,
+        },
+        role::Role,
+        storage::StorageKey,
+    },
+    utils::{hashing::hash_meme_id, moderation_helpers::assert_valid_report_reason},
+};
+
+#[near_bindgen]
+impl Moderation for HusyContract {
+    /// Deposit stays in contract until report is resolved. Reporters get it back when
+    /// meme is hidden, dismissed reports move it to treasury
+    #[payable]
+    fn report_meme(&mut self, token_id: MemeTokenId, reason: String) {
+        self.assert_not_paused();
+        assert_valid_report_reason(&reason);
+        let deposit = env::attached_deposit();
+        assert!(
+            deposit >= REPORT_DEPOSIT,
+            "Must attach {} yoctoNEAR to report meme",
+            REPORT_DEPOSIT
+        );
+        assert!(self.memes_by_id.get(&token_id).is_some(), "Meme not found");
+        assert!(!self.is_meme_hidden(&token_id), "Meme is already hidden");
+
+        let reporter_id = env::predecessor_account_id();
+        let mut meme_reports = self.reports_per_meme.get(&token_id).unwrap_or_else(|| {
+            UnorderedMap::new(
+                StorageKey::ReportsPerMemeInner {
+                    meme_id_hash: hash_meme_id(&token_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        assert!(
+            meme_reports.get(&reporter_id).is_none(),
+            "Meme is already reported by this account"
+        );
+        assert!(
+            meme_reports.len() < MAX_REPORTS_PER_MEME,
+            "Meme already has maximum number of reports"
+        );
+
+        let report_id = self.next_report_id;
+        self.next_report_id += 1;
+        self.reports.insert(
+            &report_id,
+            &Report {
+                token_id: token_id.clone(),
+                reporter_id: reporter_id.clone(),
+                reason: reason.clone(),
+                deposit,
+                reported_at: env::block_timestamp(),
+            },
+        );
+        meme_reports.insert(&reporter_id, &report_id);
+        self.reports_per_meme.insert(&token_id, &meme_reports);
+
+        HusyEvent::MemeReported(vec![MemeReportedLog {
+            report_id,
+            token_id,
+            reporter_id,
+            reason,
+        }])
+        .emit();
+    }
+
+    fn dismiss_reports(&mut self, token_id: MemeTokenId, reason: Option<String>) {
+        self.assert_owner_or_role(Role::Moderator);
+        let dismissed = self.resolve_reports(&token_id, false);
+        assert!(dismissed > 0, "Meme has no reports");
+
+        HusyEvent::ReportsDismissed(vec![ModerationDecisionLog {
+            token_id,
+            moderator_id: env::predecessor_account_id(),
+            reason,
+        }])
+        .emit();
+    }
+
+    /// Hidden meme stays owned, but is removed from feeds and cannot be promoted to main page
+    fn hide_meme(&mut self, token_id: MemeTokenId, reason: Option<String>) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert!(self.memes_by_id.get(&token_id).is_some(), "Meme not found");
+        assert!(!self.is_meme_hidden(&token_id), "Meme is already hidden");
+
+        self.remove_meme_from_feeds(&token_id);
+        self.hidden_memes.insert(&token_id);
+        self.resolve_reports(&token_id, true);
+
+        HusyEvent::MemeHidden(vec![ModerationDecisionLog {
+            token_id,
+            moderator_id: env::predecessor_account_id(),
+            reason,
+        }])
+        .emit();
+    }
+
+    fn unhide_meme(&mut self, token_id: MemeTokenId, reason: Option<String>) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert!(self.hidden_memes.remove(&token_id), "Meme is not hidden");
+
+        self.add_meme_to_feeds(&token_id);
+
+        HusyEvent::MemeUnhidden(vec![ModerationDecisionLog {
+            token_id,
+            moderator_id: env::predecessor_account_id(),
+            reason,
+        }])
+        .emit();
+    }
+
+    fn freeze_meme(&mut self, token_id: MemeTokenId, reason: Option<String>) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert!(self.memes_by_id.get(&token_id).is_some(), "Meme not found");
+        assert!(
+            self.frozen_memes.insert(&token_id),
+            "Meme is already frozen"
+        );
+
+        HusyEvent::MemeFrozen(vec![ModerationDecisionLog {
+            token_id,
+            moderator_id: env::predecessor_account_id(),
+            reason,
+        }])
+        .emit();
+    }
+
+    fn unfreeze_meme(&mut self, token_id: MemeTokenId, reason: Option<String>) {
+        self.assert_owner_or_role(Role::Moderator);
+        assert!(self.frozen_memes.remove(&token_id), "Meme is not frozen");
+
+        HusyEvent::MemeUnfrozen(vec![ModerationDecisionLog {
+            token_id,
+            moderator_id: env::predecessor_account_id(),
+            reason,
+        }])
+        .emit();
+    }
+
+    fn get_reports(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<ReportView> {
+        self.reports
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(self.reports.len()) as usize)
+            .map(|(report_id, report)| self.get_report_view(report_id, report))
+            .collect()
+    }
+
+    fn get_meme_moderation(&self, token_id: MemeTokenId) -> Option<MemeModerationView> {
+        self.memes_by_id.get(&token_id)?;
+
+        Some(MemeModerationView {
+            hidden: self.is_meme_hidden(&token_id),
+            frozen: self.frozen_memes.contains(&token_id),
+            reports: self
+                .reports_per_meme
+                .get(&token_id)
+                .map(|meme_reports| meme_reports.len())
+                .unwrap_or(0),
+            token_id,
+        })
+    }
+
+    fn get_hidden_memes(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<MemeTokenView> {
+        self.hidden_memes
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(self.hidden_memes.len()) as usize)
+            .filter_map(|meme_id| self.get_meme_view(meme_id, None))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::json_types::U128;
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{
+        ContractInit, MemeInteraction, NFTEnumeration, NFTTokenCore, TrendingFeed,
+    };
+    use crate::models::meme::MemeToken;
+    use crate::models::meme_additional_data::MemeAdditionalData;
+    use crate::models::trending::TrendingWindow;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    fn moderated_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"moderator.testnet".to_owned(), &vec![Role::Moderator]);
+        for meme_id in ["cat", "dog"] {
+            let meme_id = meme_id.to_owned();
+            contract.memes_by_id.insert(
+                &meme_id,
+                &MemeToken {
+                    owner_id: "alice.testnet".to_owned(),
+                    ..Default::default()
+                },
+            );
+            contract
+                .meme_metadata_by_id
+                .insert(&meme_id, &Default::default());
+            contract.meme_additional_data_by_id.insert(
+                &meme_id,
+                &MemeAdditionalData {
+                    showed_on_main: true,
+                    category: Some("animals".to_owned()),
+                    ..Default::default()
+                },
+            );
+            contract.add_meme_to_owner(&"alice.testnet".to_owned(), &meme_id);
+            contract.add_meme_to_feeds(&meme_id);
+            contract.add_trending_likes(&meme_id, 1);
+        }
+
+        contract
+    }
+
+    fn report(contract: &mut HusyContract, reporter_id: &str, token_id: &str) {
+        testing_env!(get_context(reporter_id, REPORT_DEPOSIT));
+        contract.report_meme(token_id.to_owned(), "Offensive".to_owned());
+    }
+
+    fn ids(memes: Vec<MemeTokenView>) -> Vec<MemeTokenId> {
+        memes.into_iter().map(|meme| meme.token_id).collect()
+    }
+
+    #[test]
+    fn report_meme_adds_report_to_queue() {
+        let mut contract = moderated_contract();
+
+        report(&mut contract, "bob.testnet", "cat");
+        report(&mut contract, "carol.testnet", "cat");
+        report(&mut contract, "bob.testnet", "dog");
+
+        let reports = contract.get_reports(None, None);
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| (
+                    report.id,
+                    report.token_id.as_str(),
+                    report.reporter_id.as_str()
+                ))
+                .collect::<Vec<(u64, &str, &str)>>(),
+            vec![
+                (0, "cat", "bob.testnet"),
+                (1, "cat", "carol.testnet"),
+                (2, "dog", "bob.testnet"),
+            ]
+        );
+        assert_eq!(reports[0].deposit, U128(REPORT_DEPOSIT));
+        assert_eq!(contract.get_reports(Some(1), Some(1))[0].id, 1);
+        assert_eq!(
+            contract
+                .get_meme_moderation("cat".to_owned())
+                .unwrap()
+                .reports,
+            2
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_reported","data":[{"report_id":2,"token_id":"dog","reporter_id":"bob.testnet","reason":"Offensive"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Must attach 10000000000000000000000 yoctoNEAR to report meme")]
+    fn report_meme_without_deposit() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("bob.testnet", REPORT_DEPOSIT - 1));
+
+        contract.report_meme("cat".to_owned(), "Offensive".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Report reason must have between 1 and 280 characters")]
+    fn report_meme_with_empty_reason() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("bob.testnet", REPORT_DEPOSIT));
+
+        contract.report_meme("cat".to_owned(), " ".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is already reported by this account")]
+    fn report_meme_twice() {
+        let mut contract = moderated_contract();
+        report(&mut contract, "bob.testnet", "cat");
+
+        report(&mut contract, "bob.testnet", "cat");
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme already has maximum number of reports")]
+    fn report_meme_over_limit() {
+        let mut contract = moderated_contract();
+        for index in 0..MAX_REPORTS_PER_MEME {
+            report(&mut contract, &format!("reporter{}.testnet", index), "cat");
+        }
+
+        report(&mut contract, "bob.testnet", "cat");
+    }
+
+    #[test]
+    fn dismiss_reports_moves_deposits_to_treasury() {
+        let mut contract = moderated_contract();
+        report(&mut contract, "bob.testnet", "cat");
+        report(&mut contract, "carol.testnet", "cat");
+        report(&mut contract, "bob.testnet", "dog");
+        testing_env!(get_context("moderator.testnet", 0));
+
+        contract.dismiss_reports("cat".to_owned(), None);
+
+        assert_eq!(contract.treasury_balance, 2 * REPORT_DEPOSIT);
+        assert_eq!(
+            contract
+                .get_reports(None, None)
+                .into_iter()
+                .map(|report| report.token_id)
+                .collect::<Vec<MemeTokenId>>(),
+            vec!["dog".to_owned()]
+        );
+        assert!(contract.reports_per_meme.get(&"cat".to_owned()).is_none());
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"reports_dismissed","data":[{"token_id":"cat","moderator_id":"moderator.testnet"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme has no reports")]
+    fn dismiss_reports_without_reports() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+
+        contract.dismiss_reports("cat".to_owned(), None);
+    }
+
+    #[test]
+    fn hide_meme_excludes_it_from_feeds() {
+        let mut contract = moderated_contract();
+        report(&mut contract, "bob.testnet", "cat");
+        testing_env!(get_context("moderator.testnet", 0));
+
+        contract.hide_meme("cat".to_owned(), Some("Spam".to_owned()));
+
+        assert_eq!(
+            ids(contract.get_memes(None, None, None, None, false)),
+            vec!["dog".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_memes(None, None, None, Some("alice.testnet".to_owned()), false)),
+            vec!["dog".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_memes(None, None, Some("animals".to_owned()), None, true)),
+            vec!["dog".to_owned()]
+        );
+        assert_eq!(ids(contract.nft_tokens(None, None)), vec!["dog".to_owned()]);
+        assert_eq!(
+            ids(contract.get_trending_memes(TrendingWindow::Day, None, None)),
+            vec!["dog".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_hidden_memes(None, None)),
+            vec!["cat".to_owned()]
+        );
+        assert_eq!(
+            contract.nft_token("cat".to_owned()).unwrap().owner_id,
+            "alice.testnet".to_owned()
+        );
+        assert!(contract.get_reports(None, None).is_empty());
+        assert_eq!(contract.treasury_balance, 0);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_hidden","data":[{"token_id":"cat","moderator_id":"moderator.testnet","reason":"Spam"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn unhide_meme_returns_it_to_feeds() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.hide_meme("cat".to_owned(), None);
+
+        contract.unhide_meme("cat".to_owned(), None);
+
+        assert_eq!(contract.get_memes(None, None, None, None, true).len(), 2);
+        assert_eq!(
+            contract
+                .get_memes(None, None, Some("animals".to_owned()), None, false)
+                .len(),
+            2
+        );
+        assert!(contract.get_hidden_memes(None, None).is_empty());
+        assert!(
+            !contract
+                .get_meme_moderation("cat".to_owned())
+                .unwrap()
+                .hidden
+        );
+    }
+
+    #[test]
+    fn hidden_meme_is_not_promoted_to_main() {
+        let mut contract = moderated_contract();
+        let meme_id = "cat".to_owned();
+        contract.meme_additional_data_by_id.insert(
+            &meme_id,
+            &MemeAdditionalData {
+                likes: 1_000,
+                ..Default::default()
+            },
+        );
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.hide_meme(meme_id.clone(), None);
+
+        let price = contract.yocto_near_price_for_like();
+        testing_env!(get_context("bob.testnet", price + 10u128.pow(22)));
+        contract.like_meme(meme_id.clone(), 1);
+
+        assert!(
+            !contract
+                .meme_additional_data_by_id
+                .get(&meme_id)
+                .unwrap()
+                .showed_on_main
+        );
+        assert!(!contract.main_page_memes.contains(&meme_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is already hidden")]
+    fn report_hidden_meme() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.hide_meme("cat".to_owned(), None);
+
+        report(&mut contract, "bob.testnet", "cat");
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner or account with Moderator role")]
+    fn hide_meme_unauthorized() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("bob.testnet", 0));
+
+        contract.hide_meme("cat".to_owned(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is not hidden")]
+    fn unhide_visible_meme() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+
+        contract.unhide_meme("cat".to_owned(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is frozen")]
+    fn freeze_meme_blocks_transfers() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.freeze_meme("cat".to_owned(), None);
+        assert!(
+            contract
+                .get_meme_moderation("cat".to_owned())
+                .unwrap()
+                .frozen
+        );
+
+        testing_env!(get_context("alice.testnet", 1));
+        contract.nft_transfer("bob.testnet".to_owned(), "cat".to_owned(), None, None);
+    }
+
+    #[test]
+    fn unfreeze_meme_allows_transfers() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.freeze_meme("cat".to_owned(), None);
+        contract.unfreeze_meme("cat".to_owned(), Some("Resolved".to_owned()));
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_frozen","data":[{"token_id":"cat","moderator_id":"moderator.testnet"}]}"#,
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_unfrozen","data":[{"token_id":"cat","moderator_id":"moderator.testnet","reason":"Resolved"}]}"#,
+            ]
+        );
+
+        testing_env!(get_context("alice.testnet", 1));
+        contract.nft_transfer("bob.testnet".to_owned(), "cat".to_owned(), None, None);
+
+        assert_eq!(
+            contract.nft_token("cat".to_owned()).unwrap().owner_id,
+            "bob.testnet".to_owned()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is already frozen")]
+    fn freeze_frozen_meme() {
+        let mut contract = moderated_contract();
+        testing_env!(get_context("moderator.testnet", 0));
+        contract.freeze_meme("cat".to_owned(), None);
+
+        contract.freeze_meme("cat".to_owned(), None);
+    }
+
+    #[test]
+    fn get_meme_moderation_of_missing_meme() {
+        let contract = moderated_contract();
+
+        assert_eq!(contract.get_meme_moderation("frog".to_owned()), None);
+    }
+}
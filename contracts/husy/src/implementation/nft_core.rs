@@ -3,13 +3,15 @@ use std::collections::HashMap;
 
 use crate::{
     contract::NFTTokenCore,
+    events::{NftEvent, NftTransferLog},
     ext_contracts::ext_nft_reciever,
     ext_self_contracts::ext_self_resolver,
     models::{
         husy::*,
         meme::{MemeTokenId, MemeTokenView},
+        provenance::ProvenanceEvent,
     },
-    utils::payment::{refund_approved_account_ids, with_refund},
+    utils::payment::refund_approved_account_ids,
 };
 
 const GAS_FOR_RESOLVE_TRANSFER: Gas = 10_000_000_000_000;
@@ -31,10 +33,8 @@ impl NFTTokenCore for HusyContract {
         memo: Option<String>,
     ) {
         let sender_id = env::predecessor_account_id();
-        with_refund(|| {
-            self.nft_meme_transfer(sender_id, receiver_id, token_id, approval_id, memo);
-            ((), None)
-        })
+        let previous = self.nft_meme_transfer(sender_id, receiver_id, token_id, approval_id, memo);
+        refund_approved_account_ids(previous.owner_id, &previous.approved_account_ids);
     }
 
     #[payable]
@@ -110,8 +110,26 @@ impl NFTTokenCore for HusyContract {
         token.owner_id = owner_id.clone();
         self.memes_by_id.insert(&token_id, &token);
         self.swap_meme_owner(&receiver_id, &owner_id, &token_id);
+        // Receiver could list or auction meme in `nft_on_transfer` before returning it
+        self.remove_listing(&token_id);
+        self.close_auction(&token_id);
+        self.record_provenance(
+            &token_id,
+            ProvenanceEvent::Revert,
+            Some(receiver_id.clone()),
+            Some(owner_id.clone()),
+        );
+
+        refund_approved_account_ids(receiver_id.clone(), &token.approved_account_ids);
 
-        refund_approved_account_ids(receiver_id, &token.approved_account_ids);
+        NftEvent::Transfer(vec![NftTransferLog {
+            authorized_id: None,
+            old_owner_id: receiver_id,
+            new_owner_id: owner_id,
+            token_ids: vec![token_id],
+            memo: None,
+        }])
+        .emit();
 
         false
     }
@@ -190,6 +208,23 @@ mod test {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn nft_transfer_when_paused() {
+        let mut context = get_context("aaa.testnet".to_owned(), 10000000);
+        context.attached_deposit = 1;
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("aaa.testnet".to_owned());
+        contract.paused = true;
+
+        contract.nft_transfer(
+            "bbb.testnet".to_owned(),
+            "meme.testnet".to_owned(),
+            None,
+            None,
+        );
+    }
+
     #[test]
     fn none_nft_token() {
         let context = get_context("aaa.testnet".to_owned(), 10000000);
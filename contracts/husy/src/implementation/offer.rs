@@ -0,0 +1,537 @@
+use near_sdk::{assert_one_yocto, env, json_types::U128, near_bindgen, AccountId};
+
+use crate::{
+    contract::Offers,
+    events::{HusyEvent, OfferAcceptedLog, OfferMadeLog, OfferRemovedLog},
+    models::{
+        husy::*,
+        meme::MemeTokenId,
+        offer::{Offer, OfferView, MAX_OFFERS_PER_MEME},
+    },
+    utils::payment::{pay_out, refund_approved_account_ids},
+};
+
+#[near_bindgen]
+impl Offers for HusyContract {
+    /// Attached deposit is escrowed as offer, new offer replaces previous one
+    /// of the same bidder. Storage is paid from storage balance of bidder
+    /// or from attached deposit, which lowers offered amount. Expired offers
+    /// stay escrowed until bidder cancels them, or until new offer needs the place
+    /// of meme at maximum number of offers
+    #[payable]
+    fn make_offer(&mut self, token_id: MemeTokenId, expires_at: u64) {
+        self.assert_not_paused();
+        let token = self.memes_by_id.get(&token_id).expect("Meme not found");
+        let bidder_id = env::predecessor_account_id();
+        assert_ne!(bidder_id, token.owner_id, "Cannot make offer on own meme");
+        self.assert_not_on_auction(&token_id);
+        let now = env::block_timestamp();
+        assert!(expires_at > now, "Offer must expire in the future");
+
+        self.refund_offer(&token_id, &bidder_id);
+        let offers_count = self
+            .offers_per_meme
+            .get(&token_id)
+            .map_or(0, |offers| offers.len());
+        if offers_count >= MAX_OFFERS_PER_MEME {
+            let evicted = self
+                .evict_expired_offer(&token_id)
+                .expect("Meme already has maximum number of offers");
+            HusyEvent::OfferCancelled(vec![evicted]).emit();
+        }
+
+        let attached = env::attached_deposit();
+        let initial_storage_usage = env::storage_usage();
+        let mut offer = Offer {
+            amount: attached,
+            created_at: now,
+            expires_at,
+        };
+        self.insert_offer(&token_id, &bidder_id, &offer);
+        offer.amount = self.pay_for_storage(&bidder_id, initial_storage_usage, attached);
+        assert!(offer.amount > 0, "Offer amount must be greater than 0");
+        self.insert_offer(&token_id, &bidder_id, &offer);
+
+        HusyEvent::OfferMade(vec![OfferMadeLog {
+            token_id,
+            bidder_id,
+            amount: U128(offer.amount),
+            expires_at,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn cancel_offer(&mut self, token_id: MemeTokenId) {
+        assert_one_yocto();
+        let bidder_id = env::predecessor_account_id();
+        let offer = self
+            .refund_offer(&token_id, &bidder_id)
+            .expect("Offer not found");
+
+        HusyEvent::OfferCancelled(vec![OfferRemovedLog {
+            token_id,
+            bidder_id,
+            amount: U128(offer.amount),
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn accept_offer(&mut self, token_id: MemeTokenId, bidder_id: AccountId) {
+        let token = self.assert_meme_owner(&token_id);
+        let offer = self
+            .offers_per_meme
+            .get(&token_id)
+            .and_then(|offers| offers.get(&bidder_id))
+            .expect("Offer not found");
+        assert!(
+            !offer.is_expired(env::block_timestamp()),
+            "Offer has expired"
+        );
+
+        self.remove_offer(&token_id, &bidder_id);
+        let payout = self.get_meme_payout(token_id.clone(), U128(offer.amount), u32::MAX);
+        let previous = self.nft_meme_transfer(
+            token.owner_id,
+            bidder_id.clone(),
+            token_id.clone(),
+            None,
+            None,
+        );
+        refund_approved_account_ids(previous.owner_id.clone(), &previous.approved_account_ids);
+        pay_out(&payout);
+        self.mark_meme_sold(&token_id, offer.amount);
+
+        HusyEvent::OfferAccepted(vec![OfferAcceptedLog {
+            token_id: token_id.clone(),
+            owner_id: previous.owner_id,
+            bidder_id,
+            amount: U128(offer.amount),
+        }])
+        .emit();
+    }
+
+    fn get_offers_for_meme(
+        &self,
+        token_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<OfferView> {
+        let offers = match self.offers_per_meme.get(&token_id) {
+            Some(offers) => offers,
+            None => return vec![],
+        };
+        let now = env::block_timestamp();
+
+        offers
+            .iter()
+            .filter(|(_, offer)| !offer.is_expired(now))
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(offers.len()) as usize)
+            .map(|(bidder_id, offer)| self.get_offer_view(token_id.clone(), bidder_id, offer))
+            .collect()
+    }
+
+    fn get_offers_by_bidder(
+        &self,
+        bidder_id: AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<OfferView> {
+        let bidder_offers = match self.offers_per_bidder.get(&bidder_id) {
+            Some(bidder_offers) => bidder_offers,
+            None => return vec![],
+        };
+        let now = env::block_timestamp();
+
+        bidder_offers
+            .iter()
+            .filter_map(|token_id| {
+                let offer = self.offers_per_meme.get(&token_id)?.get(&bidder_id)?;
+                Some((token_id, offer))
+            })
+            .filter(|(_, offer)| !offer.is_expired(now))
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(bidder_offers.len()) as usize)
+            .map(|(token_id, offer)| self.get_offer_view(token_id, bidder_id.clone(), offer))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{Auctions, ContractInit, NFTBurn, NFTTokenCore, StorageManagement};
+    use crate::models::meme::MemeToken;
+
+    use super::*;
+
+    const NOW: u64 = 1_000;
+    const EXPIRES_AT: u64 = 2_000;
+    const OFFER: Balance = 1_000_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance, now: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(now)
+            .build()
+    }
+
+    fn offers_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0, NOW));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        for meme_id in ["cat", "dog"] {
+            let meme_id = meme_id.to_owned();
+            contract.memes_by_id.insert(
+                &meme_id,
+                &MemeToken {
+                    owner_id: "alice.testnet".to_owned(),
+                    royalty: HashMap::from([("artist.testnet".to_owned(), 1_000)]),
+                    ..Default::default()
+                },
+            );
+            contract
+                .meme_metadata_by_id
+                .insert(&meme_id, &Default::default());
+            contract
+                .meme_additional_data_by_id
+                .insert(&meme_id, &Default::default());
+            contract.add_meme_to_owner(&"alice.testnet".to_owned(), &meme_id);
+        }
+
+        contract
+    }
+
+    fn make_offer(
+        contract: &mut HusyContract,
+        bidder_id: &str,
+        token_id: &str,
+        amount: Balance,
+        expires_at: u64,
+    ) {
+        testing_env!(get_context(bidder_id, amount, NOW));
+        contract.make_offer(token_id.to_owned(), expires_at);
+    }
+
+    fn offer_amounts(offers: Vec<OfferView>) -> Vec<(MemeTokenId, AccountId, U128)> {
+        offers
+            .into_iter()
+            .map(|offer| (offer.token_id, offer.bidder_id, offer.amount))
+            .collect()
+    }
+
+    #[test]
+    fn make_offer_pays_storage_from_deposit() {
+        let mut contract = offers_contract();
+
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+
+        let offers = contract.get_offers_for_meme("cat".to_owned(), None, None);
+        assert_eq!(offers.len(), 1);
+        assert_eq!(offers[0].bidder_id, "bob.testnet".to_owned());
+        assert!(offers[0].amount.0 < OFFER);
+        assert_eq!(offers[0].created_at, NOW);
+        assert_eq!(offers[0].expires_at, EXPIRES_AT);
+    }
+
+    #[test]
+    fn make_offer_pays_storage_from_storage_balance() {
+        let mut contract = offers_contract();
+        testing_env!(get_context("bob.testnet", OFFER, NOW));
+        contract.storage_deposit(None, None);
+
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+
+        assert_eq!(
+            offer_amounts(contract.get_offers_for_meme("cat".to_owned(), None, None)),
+            vec![("cat".to_owned(), "bob.testnet".to_owned(), U128(OFFER))]
+        );
+        assert_eq!(
+            get_logs(),
+            vec![format!(
+                r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"offer_made","data":[{{"token_id":"cat","bidder_id":"bob.testnet","amount":"{}","expires_at":2000}}]}}"#,
+                OFFER
+            )]
+        );
+    }
+
+    #[test]
+    fn make_offer_replaces_previous_offer() {
+        let mut contract = offers_contract();
+        testing_env!(get_context("bob.testnet", OFFER, NOW));
+        contract.storage_deposit(None, None);
+        make_offer(&mut contract, "bob.testnet", "cat", 100, EXPIRES_AT);
+
+        make_offer(&mut contract, "bob.testnet", "cat", 200, EXPIRES_AT);
+
+        assert_eq!(
+            offer_amounts(contract.get_offers_by_bidder("bob.testnet".to_owned(), None, None)),
+            vec![("cat".to_owned(), "bob.testnet".to_owned(), U128(200))]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot make offer on own meme")]
+    fn make_offer_on_own_meme() {
+        let mut contract = offers_contract();
+
+        make_offer(&mut contract, "alice.testnet", "cat", OFFER, EXPIRES_AT);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offer must expire in the future")]
+    fn make_offer_already_expired() {
+        let mut contract = offers_contract();
+
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, NOW);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offer amount must be greater than 0")]
+    fn make_offer_without_deposit() {
+        let mut contract = offers_contract();
+        testing_env!(get_context("bob.testnet", OFFER, NOW));
+        contract.storage_deposit(None, None);
+
+        make_offer(&mut contract, "bob.testnet", "cat", 0, EXPIRES_AT);
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme is on auction")]
+    fn make_offer_on_auction() {
+        let mut contract = offers_contract();
+        testing_env!(get_context("alice.testnet", OFFER, NOW));
+        contract.create_auction("cat".to_owned(), U128(100), EXPIRES_AT, U128(1));
+
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+    }
+
+    #[test]
+    fn cancel_offer_success() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        testing_env!(get_context("bob.testnet", 1, NOW));
+
+        contract.cancel_offer("cat".to_owned());
+
+        assert!(contract
+            .get_offers_for_meme("cat".to_owned(), None, None)
+            .is_empty());
+        assert!(contract
+            .get_offers_by_bidder("bob.testnet".to_owned(), None, None)
+            .is_empty());
+        assert!(contract.offers_per_meme.get(&"cat".to_owned()).is_none());
+        assert!(contract
+            .offers_per_bidder
+            .get(&"bob.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Offer not found")]
+    fn cancel_offer_not_found() {
+        let mut contract = offers_contract();
+        testing_env!(get_context("bob.testnet", 1, NOW));
+
+        contract.cancel_offer("cat".to_owned());
+    }
+
+    #[test]
+    fn accept_offer_transfers_meme() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        make_offer(&mut contract, "carol.testnet", "cat", OFFER, EXPIRES_AT);
+        let amount = contract.get_offers_for_meme("cat".to_owned(), None, Some(1))[0]
+            .amount
+            .0;
+        testing_env!(get_context("alice.testnet", 1, NOW + 1));
+
+        contract.accept_offer("cat".to_owned(), "bob.testnet".to_owned());
+
+        assert_eq!(
+            contract.nft_token("cat".to_owned()).unwrap().owner_id,
+            "bob.testnet".to_owned()
+        );
+        assert_eq!(
+            offer_amounts(contract.get_offers_for_meme("cat".to_owned(), None, None))
+                .into_iter()
+                .map(|(_, bidder_id, _)| bidder_id)
+                .collect::<Vec<AccountId>>(),
+            vec!["carol.testnet".to_owned()]
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"old_owner_id":"alice.testnet","new_owner_id":"bob.testnet","token_ids":["cat"]}]}"#.to_owned(),
+                format!(
+                    r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"offer_accepted","data":[{{"token_id":"cat","owner_id":"alice.testnet","bidder_id":"bob.testnet","amount":"{}"}}]}}"#,
+                    amount
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Predecessor must be meme owner")]
+    fn accept_offer_not_owner() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        testing_env!(get_context("carol.testnet", 1, NOW));
+
+        contract.accept_offer("cat".to_owned(), "bob.testnet".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Offer has expired")]
+    fn accept_expired_offer() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        testing_env!(get_context("alice.testnet", 1, EXPIRES_AT));
+
+        contract.accept_offer("cat".to_owned(), "bob.testnet".to_owned());
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn accept_offer_without_yocto() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        testing_env!(get_context("alice.testnet", 0, NOW));
+
+        contract.accept_offer("cat".to_owned(), "bob.testnet".to_owned());
+    }
+
+    #[test]
+    fn expired_offers_are_hidden_until_cancelled() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        make_offer(&mut contract, "bob.testnet", "dog", OFFER, EXPIRES_AT * 2);
+
+        testing_env!(get_context("carol.testnet", OFFER, EXPIRES_AT));
+        assert!(contract
+            .get_offers_for_meme("cat".to_owned(), None, None)
+            .is_empty());
+        assert_eq!(
+            contract
+                .get_offers_by_bidder("bob.testnet".to_owned(), None, None)
+                .len(),
+            1
+        );
+
+        contract.make_offer("cat".to_owned(), EXPIRES_AT * 2);
+        assert!(contract
+            .offers_per_meme
+            .get(&"cat".to_owned())
+            .unwrap()
+            .get(&"bob.testnet".to_owned())
+            .is_some());
+
+        testing_env!(get_context("bob.testnet", 1, EXPIRES_AT));
+        contract.cancel_offer("cat".to_owned());
+        assert!(contract
+            .offers_per_meme
+            .get(&"cat".to_owned())
+            .unwrap()
+            .get(&"bob.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme already has maximum number of offers")]
+    fn make_offer_over_limit() {
+        let mut contract = offers_contract();
+        for index in 0..MAX_OFFERS_PER_MEME {
+            make_offer(
+                &mut contract,
+                &format!("bidder{}.testnet", index),
+                "cat",
+                OFFER,
+                EXPIRES_AT,
+            );
+        }
+
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+    }
+
+    #[test]
+    fn make_offer_at_limit_replaces_own_offer() {
+        let mut contract = offers_contract();
+        for index in 0..MAX_OFFERS_PER_MEME {
+            make_offer(
+                &mut contract,
+                &format!("bidder{}.testnet", index),
+                "cat",
+                OFFER,
+                EXPIRES_AT,
+            );
+        }
+
+        make_offer(
+            &mut contract,
+            "bidder0.testnet",
+            "cat",
+            OFFER * 2,
+            EXPIRES_AT,
+        );
+
+        assert_eq!(
+            contract
+                .offers_per_meme
+                .get(&"cat".to_owned())
+                .unwrap()
+                .len(),
+            MAX_OFFERS_PER_MEME
+        );
+    }
+
+    #[test]
+    fn make_offer_at_limit_evicts_expired_offer() {
+        let mut contract = offers_contract();
+        for index in 0..MAX_OFFERS_PER_MEME {
+            make_offer(
+                &mut contract,
+                &format!("bidder{}.testnet", index),
+                "cat",
+                OFFER,
+                EXPIRES_AT + index,
+            );
+        }
+
+        testing_env!(get_context("bob.testnet", OFFER, EXPIRES_AT + 1));
+        contract.make_offer("cat".to_owned(), EXPIRES_AT * 2);
+
+        let offers = contract.offers_per_meme.get(&"cat".to_owned()).unwrap();
+        assert_eq!(offers.len(), MAX_OFFERS_PER_MEME);
+        assert!(offers.get(&"bidder0.testnet".to_owned()).is_none());
+        assert!(offers.get(&"bidder1.testnet".to_owned()).is_some());
+        assert!(offers.get(&"bob.testnet".to_owned()).is_some());
+        assert!(contract
+            .offers_per_bidder
+            .get(&"bidder0.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    fn burn_refunds_offers() {
+        let mut contract = offers_contract();
+        make_offer(&mut contract, "bob.testnet", "cat", OFFER, EXPIRES_AT);
+        testing_env!(get_context("alice.testnet", 1, NOW));
+
+        contract.nft_burn("cat".to_owned());
+
+        assert!(contract.offers_per_meme.get(&"cat".to_owned()).is_none());
+        assert!(contract
+            .offers_per_bidder
+            .get(&"bob.testnet".to_owned())
+            .is_none());
+    }
+}
@@ -0,0 +1,281 @@
+use near_sdk::near_bindgen;
+
+use crate::{
+    contract::Provenance,
+    models::{husy::*, meme::MemeTokenId, provenance::ProvenanceEntry},
+};
+
+#[near_bindgen]
+impl Provenance for HusyContract {
+    /// Entries are ordered from oldest to newest and are kept after burn,
+    /// until the same id is minted again
+    fn get_provenance(
+        &self,
+        token_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<ProvenanceEntry> {
+        let entries = self.provenance_per_meme.get(&token_id).unwrap_or_default();
+        let limit = limit.map_or(entries.len(), |limit| limit as usize);
+
+        entries
+            .into_iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::borsh::BorshSerialize;
+    use near_sdk::json_types::U128;
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::VMContextBuilder, testing_env, AccountId, Balance, PromiseResult, VMContext,
+    };
+
+    use crate::contract::{ContractInit, Marketplace, MintNFT, NFTBurn, NFTTokenCore};
+    use crate::models::provenance::{
+        ProvenanceEvent, MAX_PROVENANCE_ENTRIES, PROVENANCE_STORAGE_RESERVE,
+    };
+    use crate::models::role::Role;
+
+    use super::*;
+
+    const DEPOSIT: Balance = 100_000_000_000_000_000_000_000;
+    const PRICE: Balance = 1_000_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance, timestamp: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(timestamp)
+            .build()
+    }
+
+    fn minted_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0, 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"alice.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("alice.testnet", DEPOSIT, 1));
+        contract.nft_mint(
+            "cat".to_owned(),
+            Default::default(),
+            "alice.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        contract
+    }
+
+    fn entry(
+        event: ProvenanceEvent,
+        from_id: Option<&str>,
+        to_id: Option<&str>,
+        price: Option<Balance>,
+        timestamp: u64,
+    ) -> ProvenanceEntry {
+        let account = |account_id: Option<&str>| account_id.map(|id| id.to_owned() as AccountId);
+        ProvenanceEntry {
+            event,
+            from_id: account(from_id),
+            to_id: account(to_id),
+            price: price.map(U128),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn get_provenance_of_meme_lifecycle() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("alice.testnet", 1, 2));
+        contract.nft_transfer("bob.testnet".to_owned(), "cat".to_owned(), None, None);
+        testing_env!(get_context("bob.testnet", DEPOSIT, 3));
+        contract.list_meme("cat".to_owned(), U128(PRICE));
+        testing_env!(get_context("carol.testnet", PRICE + DEPOSIT, 4));
+        contract.buy_meme("cat".to_owned());
+        testing_env!(get_context("carol.testnet", 1, 5));
+        contract.nft_burn("cat".to_owned());
+
+        assert_eq!(
+            contract.get_provenance("cat".to_owned(), None, None),
+            vec![
+                entry(ProvenanceEvent::Mint, None, Some("alice.testnet"), None, 1),
+                entry(
+                    ProvenanceEvent::Transfer,
+                    Some("alice.testnet"),
+                    Some("bob.testnet"),
+                    None,
+                    2
+                ),
+                entry(
+                    ProvenanceEvent::Sale,
+                    Some("bob.testnet"),
+                    Some("carol.testnet"),
+                    Some(PRICE),
+                    4
+                ),
+                entry(ProvenanceEvent::Burn, Some("carol.testnet"), None, None, 5),
+            ]
+        );
+        assert_eq!(
+            contract.get_provenance("cat".to_owned(), Some(1), Some(1)),
+            vec![entry(
+                ProvenanceEvent::Transfer,
+                Some("alice.testnet"),
+                Some("bob.testnet"),
+                None,
+                2
+            )]
+        );
+        assert!(contract
+            .get_provenance("dog".to_owned(), None, None)
+            .is_empty());
+    }
+
+    #[test]
+    fn get_provenance_keeps_mint_when_full() {
+        let mut contract = minted_contract();
+        let owners = ["alice.testnet", "bob.testnet"];
+
+        for transfer in 0..MAX_PROVENANCE_ENTRIES {
+            testing_env!(get_context(owners[transfer % 2], 1, 2 + transfer as u64));
+            contract.nft_transfer(
+                owners[(transfer + 1) % 2].to_owned(),
+                "cat".to_owned(),
+                None,
+                None,
+            );
+        }
+
+        let entries = contract.get_provenance("cat".to_owned(), None, None);
+        assert_eq!(entries.len(), MAX_PROVENANCE_ENTRIES);
+        assert_eq!(entries[0].event, ProvenanceEvent::Mint);
+        assert_eq!(entries[1].timestamp, 3);
+        assert_eq!(
+            entries.last().unwrap().timestamp,
+            1 + MAX_PROVENANCE_ENTRIES as u64
+        );
+    }
+
+    #[test]
+    fn provenance_of_longest_accounts_fits_reserved_storage() {
+        let mut contract = minted_contract();
+        let owners = ["a".repeat(64), "b".repeat(64)];
+        let minted_log_size = contract
+            .provenance_per_meme
+            .get(&"cat".to_owned())
+            .unwrap()
+            .try_to_vec()
+            .unwrap()
+            .len();
+        testing_env!(get_context("alice.testnet", 1, 2));
+        contract.nft_transfer(owners[0].clone(), "cat".to_owned(), None, None);
+
+        for transfer in 0..MAX_PROVENANCE_ENTRIES {
+            testing_env!(get_context(
+                &owners[transfer % 2],
+                PRICE,
+                3 + transfer as u64
+            ));
+            contract.list_meme("cat".to_owned(), U128(PRICE));
+            testing_env!(get_context(
+                &owners[(transfer + 1) % 2],
+                PRICE + DEPOSIT,
+                3 + transfer as u64
+            ));
+            contract.buy_meme("cat".to_owned());
+        }
+
+        let log_size = contract
+            .provenance_per_meme
+            .get(&"cat".to_owned())
+            .unwrap()
+            .try_to_vec()
+            .unwrap()
+            .len();
+        assert!((log_size - minted_log_size) as u64 <= PROVENANCE_STORAGE_RESERVE);
+    }
+
+    #[test]
+    fn remint_of_burned_meme_starts_new_provenance() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("alice.testnet", 1, 2));
+        contract.nft_burn("cat".to_owned());
+
+        contract
+            .roles_per_account
+            .insert(&"bob.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("bob.testnet", DEPOSIT, 3));
+        contract.nft_mint(
+            "cat".to_owned(),
+            Default::default(),
+            "bob.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            contract.get_provenance("cat".to_owned(), None, None),
+            vec![entry(
+                ProvenanceEvent::Mint,
+                None,
+                Some("bob.testnet"),
+                None,
+                3
+            )]
+        );
+    }
+
+    #[test]
+    fn get_provenance_after_reverted_transfer_call() {
+        let mut contract = minted_contract();
+        testing_env!(get_context("alice.testnet", 1, 2));
+        contract.nft_transfer_call(
+            "market.testnet".to_owned(),
+            "cat".to_owned(),
+            None,
+            None,
+            "".to_owned(),
+        );
+        testing_env!(
+            get_context("husy.testnet", 0, 3),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            vec![PromiseResult::Successful(b"true".to_vec())]
+        );
+        contract.nft_resolve_transfer(
+            "alice.testnet".to_owned(),
+            "market.testnet".to_owned(),
+            "cat".to_owned(),
+            Default::default(),
+        );
+
+        assert_eq!(
+            contract.get_provenance("cat".to_owned(), Some(1), None),
+            vec![
+                entry(
+                    ProvenanceEvent::Transfer,
+                    Some("alice.testnet"),
+                    Some("market.testnet"),
+                    None,
+                    2
+                ),
+                entry(
+                    ProvenanceEvent::Revert,
+                    Some("market.testnet"),
+                    Some("alice.testnet"),
+                    None,
+                    3
+                ),
+            ]
+        );
+    }
+}
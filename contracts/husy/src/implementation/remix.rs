@@ -0,0 +1,310 @@
+use near_sdk::near_bindgen;
+
+use crate::{
+    contract::Remixes,
+    models::{
+        husy::*,
+        meme::{MemeTokenId, MemeTokenView},
+        remix::MAX_REMIX_ROYALTY,
+    },
+};
+
+#[near_bindgen]
+impl Remixes for HusyContract {
+    fn get_remix_royalty(&self) -> u32 {
+        self.remix_royalty
+    }
+
+    fn set_remix_royalty(&mut self, remix_royalty: u32) {
+        self.assert_owner();
+        assert!(
+            remix_royalty <= MAX_REMIX_ROYALTY,
+            "Remix royalty cannot be bigger than {}",
+            MAX_REMIX_ROYALTY
+        );
+
+        self.remix_royalty = remix_royalty;
+    }
+
+    fn get_remixes(
+        &self,
+        meme_id: MemeTokenId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView> {
+        let remixes = match self.remixes_per_meme.get(&meme_id) {
+            Some(remixes) => remixes,
+            None => return vec![],
+        };
+
+        remixes
+            .iter()
+            .filter(|remix_id| !self.is_meme_hidden(remix_id))
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(remixes.len()) as usize)
+            .filter_map(|remix_id| self.get_meme_view(remix_id, None))
+            .collect()
+    }
+
+    fn get_lineage(&self, meme_id: MemeTokenId, limit: Option<u64>) -> Vec<MemeTokenId> {
+        self.get_lineage_ids(&meme_id, limit.unwrap_or(u64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, AccountId, Balance, VMContext,
+    };
+
+    use crate::contract::{ContractInit, MintNFT, NFTBurn, NFTTokenCore};
+    use crate::models::role::Role;
+
+    use super::*;
+
+    const DEPOSIT: Balance = 100_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    fn mint(
+        contract: &mut HusyContract,
+        creator_id: &str,
+        token_id: &str,
+        royalties: Option<HashMap<AccountId, u32>>,
+        remix_of: Option<&str>,
+    ) {
+        contract
+            .roles_per_account
+            .insert(&creator_id.to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context(creator_id, DEPOSIT));
+        contract.nft_mint(
+            token_id.to_owned(),
+            Default::default(),
+            creator_id.to_owned(),
+            royalties,
+            None,
+            remix_of.map(|remix_of| remix_of.to_owned()),
+        );
+    }
+
+    fn remixed_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        mint(&mut contract, "alice.testnet", "cat", None, None);
+        mint(&mut contract, "bob.testnet", "cat-hat", None, Some("cat"));
+        mint(
+            &mut contract,
+            "carol.testnet",
+            "cat-hat-2",
+            None,
+            Some("cat-hat"),
+        );
+
+        contract
+    }
+
+    #[test]
+    fn nft_mint_remix_adds_parent_creator_royalty() {
+        let mut contract = remixed_contract();
+        let royalty = |contract: &HusyContract, meme_id: &str| {
+            contract.nft_token(meme_id.to_owned()).unwrap().royalty
+        };
+
+        assert_eq!(royalty(&contract, "cat"), HashMap::new());
+        assert_eq!(
+            royalty(&contract, "cat-hat"),
+            HashMap::from([("alice.testnet".to_owned(), 500)])
+        );
+        assert_eq!(
+            royalty(&contract, "cat-hat-2"),
+            HashMap::from([("bob.testnet".to_owned(), 500)])
+        );
+
+        mint(
+            &mut contract,
+            "dave.testnet",
+            "cat-hat-3",
+            Some(HashMap::from([("bob.testnet".to_owned(), 100)])),
+            Some("cat-hat"),
+        );
+        assert_eq!(
+            royalty(&contract, "cat-hat-3"),
+            HashMap::from([("bob.testnet".to_owned(), 600)])
+        );
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[{"owner_id":"dave.testnet","token_ids":["cat-hat-3"]}]}"#,
+                r#"EVENT_JSON:{"standard":"husy","version":"1.0.0","event":"meme_remixed","data":[{"token_id":"cat-hat-3","remix_of":"cat-hat","parent_creator_id":"bob.testnet","royalty":500}]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn get_remixes_and_lineage() {
+        let mut contract = remixed_contract();
+        mint(
+            &mut contract,
+            "dave.testnet",
+            "cat-hat-3",
+            None,
+            Some("cat-hat"),
+        );
+
+        assert_eq!(
+            contract
+                .get_remixes("cat-hat".to_owned(), None, None)
+                .into_iter()
+                .map(|meme| meme.token_id)
+                .collect::<Vec<MemeTokenId>>(),
+            vec!["cat-hat-2".to_owned(), "cat-hat-3".to_owned()]
+        );
+        assert_eq!(
+            contract.get_remixes("cat-hat".to_owned(), Some(1), Some(1))[0].token_id,
+            "cat-hat-3".to_owned()
+        );
+        assert!(contract
+            .get_remixes("cat-hat-2".to_owned(), None, None)
+            .is_empty());
+        assert_eq!(
+            contract.get_lineage("cat-hat-2".to_owned(), None),
+            vec!["cat-hat".to_owned(), "cat".to_owned()]
+        );
+        assert_eq!(
+            contract.get_lineage("cat-hat-2".to_owned(), Some(1)),
+            vec!["cat-hat".to_owned()]
+        );
+        assert!(contract.get_lineage("cat".to_owned(), None).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Remixed meme not found")]
+    fn nft_mint_remix_of_missing_meme() {
+        let mut contract = remixed_contract();
+
+        mint(&mut contract, "dave.testnet", "frog", None, Some("dog"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add more than 5 royalities account")]
+    fn nft_mint_remix_exceeds_royalty_accounts() {
+        let mut contract = remixed_contract();
+        let royalties = (0..5)
+            .map(|index| (format!("account{}.testnet", index), 10))
+            .collect();
+
+        mint(
+            &mut contract,
+            "dave.testnet",
+            "frog",
+            Some(royalties),
+            Some("cat"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Sum of royalities cannot be bigger than 10 000")]
+    fn nft_mint_remix_exceeds_royalty_sum() {
+        let mut contract = remixed_contract();
+
+        mint(
+            &mut contract,
+            "dave.testnet",
+            "frog",
+            Some(HashMap::from([("dave.testnet".to_owned(), 9_500)])),
+            Some("cat"),
+        );
+    }
+
+    #[test]
+    fn set_remix_royalty_by_owner() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("owner.testnet", 0));
+
+        contract.set_remix_royalty(0);
+        assert_eq!(contract.get_remix_royalty(), 0);
+
+        mint(&mut contract, "dave.testnet", "frog", None, Some("cat"));
+        assert!(contract
+            .nft_token("frog".to_owned())
+            .unwrap()
+            .royalty
+            .is_empty());
+        assert_eq!(
+            contract.get_lineage("frog".to_owned(), None),
+            vec!["cat".to_owned()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Remix royalty cannot be bigger than 5000")]
+    fn set_remix_royalty_too_big() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("owner.testnet", 0));
+
+        contract.set_remix_royalty(5_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn set_remix_royalty_by_not_owner() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("alice.testnet", 0));
+
+        contract.set_remix_royalty(100);
+    }
+
+    #[test]
+    fn burn_detaches_meme_from_lineage() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.nft_burn("cat-hat".to_owned());
+
+        assert!(contract
+            .get_remixes("cat".to_owned(), None, None)
+            .is_empty());
+        assert!(contract.get_lineage("cat-hat".to_owned(), None).is_empty());
+        assert!(contract
+            .get_lineage("cat-hat-2".to_owned(), None)
+            .is_empty());
+        assert_eq!(
+            contract.remix_parents.get(&"cat-hat-2".to_owned()),
+            Some("cat-hat".to_owned())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme id is still used by remixes of burned meme")]
+    fn nft_mint_burned_meme_with_remixes() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+        contract.nft_burn("cat-hat".to_owned());
+
+        mint(&mut contract, "dave.testnet", "cat-hat", None, None);
+    }
+
+    #[test]
+    fn nft_mint_burned_meme_after_remixes_are_burned() {
+        let mut contract = remixed_contract();
+        testing_env!(get_context("bob.testnet", 1));
+        contract.nft_burn("cat-hat".to_owned());
+        testing_env!(get_context("carol.testnet", 1));
+        contract.nft_burn("cat-hat-2".to_owned());
+
+        mint(&mut contract, "dave.testnet", "cat-hat", None, None);
+
+        assert!(contract
+            .get_remixes("cat-hat".to_owned(), None, None)
+            .is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+use near_sdk::{assert_one_yocto, json_types::U128, near_bindgen, AccountId, Promise};
+
+use crate::{
+    contract::LikesRevenue,
+    models::{husy::*, like_revenue_split::LikeRevenueSplit, role::Role},
+    utils::revenue_helpers::withdraw_from,
+};
+
+#[near_bindgen]
+impl LikesRevenue for HusyContract {
+    fn get_like_revenue_split(&self) -> LikeRevenueSplit {
+        self.like_revenue_split.get().unwrap()
+    }
+
+    fn set_like_revenue_split(&mut self, split: LikeRevenueSplit) {
+        self.assert_owner();
+        split.assert_valid();
+
+        self.like_revenue_split.set(&split);
+    }
+
+    fn get_treasury_balance(&self) -> U128 {
+        U128(self.treasury_balance)
+    }
+
+    fn get_reward_pool_balance(&self) -> U128 {
+        U128(self.reward_pool_balance)
+    }
+
+    #[payable]
+    fn withdraw_treasury(&mut self, receiver_id: AccountId, amount: U128) -> Promise {
+        assert_one_yocto();
+        self.assert_owner_or_role(Role::Treasurer);
+        withdraw_from(&mut self.treasury_balance, amount.0);
+
+        Promise::new(receiver_id).transfer(amount.0)
+    }
+
+    #[payable]
+    fn withdraw_reward_pool(&mut self, receiver_id: AccountId, amount: U128) -> Promise {
+        assert_one_yocto();
+        self.assert_owner_or_role(Role::Treasurer);
+        withdraw_from(&mut self.reward_pool_balance, amount.0);
+
+        Promise::new(receiver_id).transfer(amount.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::ContractInit;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str, attached: u128) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    #[test]
+    fn set_like_revenue_split_success() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let split = LikeRevenueSplit {
+            creator_share: 5_000,
+            treasury_share: 2_500,
+            reward_pool_share: 2_500,
+        };
+
+        contract.set_like_revenue_split(split.clone());
+
+        assert_eq!(contract.get_like_revenue_split(), split);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sum of revenue shares must be equal to 10 000")]
+    fn set_like_revenue_split_invalid_sum() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_like_revenue_split(LikeRevenueSplit {
+            creator_share: 5_000,
+            treasury_share: 2_500,
+            reward_pool_share: 2_000,
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn set_like_revenue_split_not_owner() {
+        testing_env!(get_context("someone.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.set_like_revenue_split(LikeRevenueSplit::new());
+    }
+
+    #[test]
+    fn withdraw_treasury_success() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.treasury_balance = 1_000;
+
+        contract.withdraw_treasury("receiver.testnet".to_owned(), U128(400));
+
+        assert_eq!(contract.get_treasury_balance(), U128(600));
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough funds")]
+    fn withdraw_reward_pool_more_than_available() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.reward_pool_balance = 100;
+
+        contract.withdraw_reward_pool("receiver.testnet".to_owned(), U128(101));
+    }
+
+    #[test]
+    fn withdraw_reward_pool_by_treasurer() {
+        testing_env!(get_context("owner.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.reward_pool_balance = 100;
+        contract
+            .roles_per_account
+            .insert(&"treasurer.testnet".to_owned(), &vec![Role::Treasurer]);
+
+        testing_env!(get_context("treasurer.testnet", 1));
+        contract.withdraw_reward_pool("rewards.testnet".to_owned(), U128(100));
+
+        assert_eq!(contract.get_reward_pool_balance(), U128(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner or account with Treasurer role")]
+    fn withdraw_reward_pool_not_owner() {
+        testing_env!(get_context("someone.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.reward_pool_balance = 100;
+
+        contract.withdraw_reward_pool("someone.testnet".to_owned(), U128(100));
+    }
+}
@@ -1,40 +1,46 @@
-use near_sdk::{env, json_types::U128, AccountId};
+use near_sdk::{env, json_types::U128, near_bindgen, AccountId};
 
 use crate::{
     contract::NFTRoyality,
     models::{husy::*, meme::MemeTokenId, payout::Payout},
-    utils::payment::with_refund,
+    utils::payment::refund_approved_account_ids,
 };
 
+#[near_bindgen]
 impl NFTRoyality for HusyContract {
-    fn nft_payout(&self, token_id: MemeTokenId, balance: U128, max_len_payout: u32) -> Payout {
-        self.get_meme_payout(token_id, balance, max_len_payout)
+    fn nft_payout(
+        &self,
+        token_id: MemeTokenId,
+        balance: U128,
+        max_len_payout: Option<u32>,
+    ) -> Payout {
+        self.get_meme_payout(token_id, balance, max_len_payout.unwrap_or(u32::MAX))
     }
 
+    /// Payout is calculated for owner before transfer
+    #[payable]
     fn nft_transfer_payout(
         &mut self,
         receiver_id: AccountId,
         token_id: MemeTokenId,
-        approval_id: u64,
+        approval_id: Option<u64>,
         memo: Option<String>,
         balance: U128,
-        max_len_payout: u32,
+        max_len_payout: Option<u32>,
     ) -> Payout {
         let sender_id = env::predecessor_account_id();
+        let payout = self.get_meme_payout(
+            token_id.clone(),
+            balance,
+            max_len_payout.unwrap_or(u32::MAX),
+        );
+
+        let previous =
+            self.nft_meme_transfer(sender_id, receiver_id, token_id.clone(), approval_id, memo);
+        refund_approved_account_ids(previous.owner_id, &previous.approved_account_ids);
+        self.mark_meme_sold(&token_id, balance.0);
 
-        with_refund(|| {
-            let previous = self.nft_meme_transfer(
-                sender_id,
-                receiver_id,
-                token_id.clone(),
-                Some(approval_id),
-                memo,
-            );
-            (
-                self.get_meme_payout(token_id, balance, max_len_payout),
-                Some(previous.owner_id),
-            )
-        })
+        payout
     }
 }
 
@@ -46,7 +52,7 @@ mod test {
     use near_sdk::MockedBlockchain;
     use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
 
-    use crate::contract::ContractInit;
+    use crate::contract::{ContractInit, NFTTokenCore};
     use crate::models::meme::MemeToken;
 
     fn get_context(predecessor_account_id: String, attached: u128) -> VMContext {
@@ -77,7 +83,7 @@ mod test {
             },
         );
 
-        contract.nft_payout(meme_id.clone(), U128(100_000), 1);
+        contract.nft_payout(meme_id.clone(), U128(100_000), Some(1));
     }
 
     #[test]
@@ -101,7 +107,7 @@ mod test {
             },
         );
 
-        let result = contract.nft_payout(meme_id.clone(), U128(100_000), 10);
+        let result = contract.nft_payout(meme_id.clone(), U128(100_000), Some(10));
 
         assert_eq!(
             result,
@@ -114,4 +120,235 @@ mod test {
             }
         )
     }
+
+    fn nep199_contract(royalty: HashMap<AccountId, u32>) -> (HusyContract, MemeTokenId) {
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        let meme_id = "meme".to_owned();
+        contract.memes_by_id.insert(
+            &meme_id,
+            &MemeToken {
+                owner_id: "alice.testnet".to_owned(),
+                approved_account_ids: HashMap::from([("market.testnet".to_owned(), 4)]),
+                next_approval_id: 5,
+                royalty,
+            },
+        );
+        contract
+            .meme_metadata_by_id
+            .insert(&meme_id, &Default::default());
+        contract
+            .meme_additional_data_by_id
+            .insert(&meme_id, &Default::default());
+        contract.add_meme_to_owner(&"alice.testnet".to_owned(), &meme_id);
+
+        (contract, meme_id)
+    }
+
+    fn payout_of(entries: &[(&str, u128)]) -> Payout {
+        Payout {
+            payout: entries
+                .iter()
+                .map(|(account_id, amount)| (account_id.to_string(), U128(*amount)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn nep199_payout_splits_balance_between_royalties_and_owner() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (contract, meme_id) = nep199_contract(HashMap::from([
+            ("bob.testnet".to_owned(), 1_000),
+            ("carol.testnet".to_owned(), 500),
+        ]));
+
+        assert_eq!(
+            contract.nft_payout(meme_id, U128(1_000_000), Some(3)),
+            payout_of(&[
+                ("bob.testnet", 100_000),
+                ("carol.testnet", 50_000),
+                ("alice.testnet", 850_000),
+            ])
+        );
+    }
+
+    #[test]
+    fn nep199_payout_routes_dust_to_owner() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (contract, meme_id) = nep199_contract(HashMap::from([
+            ("bob.testnet".to_owned(), 3_333),
+            ("carol.testnet".to_owned(), 3_333),
+        ]));
+
+        let payout = contract.nft_payout(meme_id, U128(10), None);
+
+        assert_eq!(
+            payout,
+            payout_of(&[
+                ("bob.testnet", 3),
+                ("carol.testnet", 3),
+                ("alice.testnet", 4),
+            ])
+        );
+        assert_eq!(
+            payout.payout.values().map(|amount| amount.0).sum::<u128>(),
+            10
+        );
+    }
+
+    #[test]
+    fn nep199_payout_without_royalties_pays_owner() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (contract, meme_id) = nep199_contract(HashMap::new());
+
+        assert_eq!(
+            contract.nft_payout(meme_id, U128(1_000), Some(1)),
+            payout_of(&[("alice.testnet", 1_000)])
+        );
+    }
+
+    #[test]
+    fn nep199_owner_royalty_is_merged_into_owner_payout() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (contract, meme_id) = nep199_contract(HashMap::from([
+            ("alice.testnet".to_owned(), 1_000),
+            ("bob.testnet".to_owned(), 1_000),
+        ]));
+
+        assert_eq!(
+            contract.nft_payout(meme_id, U128(1_000), Some(2)),
+            payout_of(&[("bob.testnet", 100), ("alice.testnet", 900)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Market cannot payout to that many receivers")]
+    fn nep199_max_len_payout_counts_owner() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (contract, meme_id) = nep199_contract(HashMap::from([
+            ("bob.testnet".to_owned(), 1_000),
+            ("carol.testnet".to_owned(), 500),
+        ]));
+
+        contract.nft_payout(meme_id, U128(1_000), Some(2));
+    }
+
+    #[test]
+    fn nep199_transfer_payout_by_approved_account() {
+        testing_env!(get_context("market.testnet".to_owned(), 1));
+        let royalty = HashMap::from([("bob.testnet".to_owned(), 2_000)]);
+        let (mut contract, meme_id) = nep199_contract(royalty.clone());
+
+        let payout = contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id.clone(),
+            Some(4),
+            None,
+            U128(1_000),
+            Some(2),
+        );
+
+        assert_eq!(
+            payout,
+            payout_of(&[("bob.testnet", 200), ("alice.testnet", 800)])
+        );
+        let token = contract.nft_token(meme_id.clone()).unwrap();
+        assert_eq!(token.owner_id, "dave.testnet".to_owned());
+        assert_eq!(token.royalty, royalty);
+        assert!(token.approved_account_ids.is_empty());
+        assert!(contract
+            .memes_per_owner
+            .get(&"dave.testnet".to_owned())
+            .unwrap()
+            .contains(&meme_id));
+        assert!(contract
+            .memes_per_owner
+            .get(&"alice.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    fn nep199_transfer_payout_by_owner() {
+        testing_env!(get_context("alice.testnet".to_owned(), 1));
+        let (mut contract, meme_id) = nep199_contract(HashMap::new());
+
+        let payout = contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id.clone(),
+            None,
+            Some("sale".to_owned()),
+            U128(1_000),
+            None,
+        );
+
+        assert_eq!(payout, payout_of(&[("alice.testnet", 1_000)]));
+        assert_eq!(
+            contract.nft_token(meme_id).unwrap().owner_id,
+            "dave.testnet".to_owned()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn nep199_transfer_payout_requires_one_yocto() {
+        testing_env!(get_context("market.testnet".to_owned(), 0));
+        let (mut contract, meme_id) = nep199_contract(HashMap::new());
+
+        contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id,
+            Some(4),
+            None,
+            U128(1_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Sender is not approved")]
+    fn nep199_transfer_payout_with_outdated_approval_id() {
+        testing_env!(get_context("market.testnet".to_owned(), 1));
+        let (mut contract, meme_id) = nep199_contract(HashMap::new());
+
+        contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id,
+            Some(3),
+            None,
+            U128(1_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn nep199_transfer_payout_by_unapproved_account() {
+        testing_env!(get_context("eve.testnet".to_owned(), 1));
+        let (mut contract, meme_id) = nep199_contract(HashMap::new());
+
+        contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id,
+            None,
+            None,
+            U128(1_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Market cannot payout to that many receivers")]
+    fn nep199_transfer_payout_checks_max_len_payout_before_transfer() {
+        testing_env!(get_context("market.testnet".to_owned(), 1));
+        let (mut contract, meme_id) =
+            nep199_contract(HashMap::from([("bob.testnet".to_owned(), 1_000)]));
+
+        contract.nft_transfer_payout(
+            "dave.testnet".to_owned(),
+            meme_id,
+            Some(4),
+            None,
+            U128(1_000),
+            Some(1),
+        );
+    }
 }
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+
+use near_sdk::{assert_one_yocto, env, json_types::U128, near_bindgen, AccountId, Promise};
+
+use crate::{
+    contract::Editions,
+    events::{EditionSoldLog, HusyEvent, SeriesCreatedLog, SeriesPriceUpdatedLog},
+    models::{
+        category::CategoryId,
+        husy::*,
+        meme::{MemeTokenId, MemeTokenView},
+        meme_metadata::MemeTokenMetadata,
+        provenance::PROVENANCE_STORAGE_RESERVE,
+        series::{Series, SeriesId, SeriesView},
+    },
+    utils::mint_helpers::assert_valid_royalty,
+};
+
+fn assert_valid_price(price: &Option<U128>) {
+    if let Some(price) = price {
+        assert!(price.0 > 0, "Price must be greater than 0");
+    }
+}
+
+#[near_bindgen]
+impl Editions for HusyContract {
+    /// Storage is paid by creator from storage balance or attached deposit
+    #[payable]
+    fn create_series(
+        &mut self,
+        metadata: MemeTokenMetadata,
+        max_copies: u64,
+        price: Option<U128>,
+        royalties: Option<HashMap<AccountId, u32>>,
+        category: Option<CategoryId>,
+    ) -> SeriesId {
+        self.assert_not_paused();
+        assert!(max_copies > 0, "Series must have at least 1 copy");
+        self.assert_valid_meme_metadata(&metadata);
+        assert_valid_price(&price);
+        let royalty = royalties.unwrap_or_default();
+        assert_valid_royalty(&royalty);
+        if let Some(category_id) = &category {
+            let category = self
+                .categories
+                .get(category_id)
+                .unwrap_or_else(|| panic!("Category {} does not exist", category_id));
+            assert!(!category.retired, "Category {} is retired", category_id);
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        let creator_id = env::predecessor_account_id();
+        let series_id = self.next_series_id;
+        self.next_series_id += 1;
+        self.series.insert(
+            &series_id,
+            &Series {
+                creator_id: creator_id.clone(),
+                metadata: MemeTokenMetadata {
+                    copies: Some(max_copies),
+                    ..metadata
+                },
+                royalty,
+                category,
+                max_copies,
+                minted: 0,
+                price: price.map(|price| price.0),
+            },
+        );
+        self.pay_for_storage_and_refund(initial_storage_usage);
+
+        HusyEvent::SeriesCreated(vec![SeriesCreatedLog {
+            series_id,
+            creator_id,
+            max_copies,
+            price,
+        }])
+        .emit();
+
+        series_id
+    }
+
+    #[payable]
+    fn set_series_price(&mut self, series_id: SeriesId, price: Option<U128>) {
+        assert_one_yocto();
+        assert_valid_price(&price);
+        let mut series = self.series.get(&series_id).expect("Series not found");
+        assert_eq!(
+            series.creator_id,
+            env::predecessor_account_id(),
+            "Only series creator can change its price"
+        );
+
+        series.price = price.map(|price| price.0);
+        self.series.insert(&series_id, &series);
+
+        HusyEvent::SeriesPriceUpdated(vec![SeriesPriceUpdatedLog { series_id, price }]).emit();
+    }
+
+    #[payable]
+    fn mint_edition(&mut self, series_id: SeriesId, receiver_id: AccountId) -> MemeTokenId {
+        self.assert_not_paused();
+        let series = self.series.get(&series_id).expect("Series not found");
+        assert_eq!(
+            series.creator_id,
+            env::predecessor_account_id(),
+            "Only series creator can mint editions"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+        let token_id = self.mint_next_edition(series_id, receiver_id);
+        self.pay_for_storage_and_refund(initial_storage_usage - PROVENANCE_STORAGE_RESERVE);
+
+        token_id
+    }
+
+    /// Price goes to series creator, storage of edition is paid by buyer
+    #[payable]
+    fn buy_edition(&mut self, series_id: SeriesId) -> MemeTokenId {
+        self.assert_not_paused();
+        let series = self.series.get(&series_id).expect("Series not found");
+        let price = series.price.expect("Series is not for sale");
+        let attached = env::attached_deposit();
+        assert!(
+            attached >= price,
+            "Not enought deposit attached. You need at least: {} yoctoNEAR",
+            price
+        );
+
+        let buyer_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
+        let token_id = self.mint_next_edition(series_id, buyer_id.clone());
+        self.record_sale(&token_id, price);
+        let refund = self.pay_for_storage(
+            &buyer_id,
+            initial_storage_usage - PROVENANCE_STORAGE_RESERVE,
+            attached - price,
+        );
+        Promise::new(series.creator_id.clone()).transfer(price);
+
+        HusyEvent::EditionSold(vec![EditionSoldLog {
+            series_id,
+            token_id: token_id.clone(),
+            creator_id: series.creator_id,
+            buyer_id: buyer_id.clone(),
+            price: U128(price),
+        }])
+        .emit();
+
+        if refund > 0 {
+            Promise::new(buyer_id).transfer(refund);
+        }
+
+        token_id
+    }
+
+    fn get_series(&self, series_id: SeriesId) -> Option<SeriesView> {
+        self.get_series_view(series_id)
+    }
+
+    fn get_series_list(&self, from_index: Option<u128>, limit: Option<u64>) -> Vec<SeriesView> {
+        self.series
+            .keys()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(self.series.len()) as usize)
+            .filter_map(|series_id| self.get_series_view(series_id))
+            .collect()
+    }
+
+    fn get_series_editions(
+        &self,
+        series_id: SeriesId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView> {
+        let editions = match self.editions_per_series.get(&series_id) {
+            Some(editions) => editions,
+            None => return vec![],
+        };
+
+        editions
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(editions.len()) as usize)
+            .filter_map(|token_id| self.get_meme_view(token_id, None))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::json_types::Base64VecU8;
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{
+        CategoryRegistry, ContractInit, MintNFT, NFTBurn, NFTEnumeration, NFTTokenCore,
+        StorageManagement,
+    };
+    use crate::models::role::Role;
+
+    use super::*;
+
+    const DEPOSIT: Balance = 100_000_000_000_000_000_000_000;
+    const PRICE: Balance = 1_000_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    fn series_contract(price: Option<Balance>) -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract.add_category("cats".to_owned(), "Cats".to_owned());
+
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        contract.create_series(
+            MemeTokenMetadata {
+                title: Some("Cat".to_owned()),
+                media: Some("ipfs://cat.png".to_owned()),
+                media_hash: Some(Base64VecU8(vec![1; 32])),
+                ..Default::default()
+            },
+            3,
+            price.map(U128),
+            Some(HashMap::from([("alice.testnet".to_owned(), 1_000)])),
+            Some("cats".to_owned()),
+        );
+
+        contract
+    }
+
+    #[test]
+    fn create_series_success() {
+        let contract = series_contract(Some(PRICE));
+
+        assert_eq!(
+            contract.get_series(0),
+            Some(SeriesView {
+                series_id: 0,
+                creator_id: "alice.testnet".to_owned(),
+                metadata: MemeTokenMetadata {
+                    title: Some("Cat".to_owned()),
+                    media: Some("ipfs://cat.png".to_owned()),
+                    media_hash: Some(Base64VecU8(vec![1; 32])),
+                    copies: Some(3),
+                    ..Default::default()
+                },
+                royalty: HashMap::from([("alice.testnet".to_owned(), 1_000)]),
+                category: Some("cats".to_owned()),
+                max_copies: 3,
+                minted: 0,
+                supply: 0,
+                price: Some(U128(PRICE)),
+            })
+        );
+        assert_eq!(contract.get_series_list(None, None).len(), 1);
+        assert_eq!(
+            get_logs(),
+            vec![format!(
+                r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"series_created","data":[{{"series_id":0,"creator_id":"alice.testnet","max_copies":3,"price":"{}"}}]}}"#,
+                PRICE
+            )]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Series must have at least 1 copy")]
+    fn create_series_without_copies() {
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.create_series(Default::default(), 0, None, None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Category dogs does not exist")]
+    fn create_series_with_missing_category() {
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.create_series(Default::default(), 1, None, None, Some("dogs".to_owned()));
+    }
+
+    #[test]
+    fn mint_edition_shares_series_metadata() {
+        let mut contract = series_contract(None);
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+
+        let first = contract.mint_edition(0, "bob.testnet".to_owned());
+        let second = contract.mint_edition(0, "carol.testnet".to_owned());
+
+        assert_eq!(first, "0:1".to_owned());
+        assert_eq!(second, "0:2".to_owned());
+        assert!(contract.meme_metadata_by_id.get(&first).is_none());
+        let edition = contract.nft_token(second.clone()).unwrap();
+        assert_eq!(edition.owner_id, "carol.testnet".to_owned());
+        assert_eq!(edition.metadata.title, Some("Cat #2".to_owned()));
+        assert_eq!(edition.metadata.media, Some("ipfs://cat.png".to_owned()));
+        assert_eq!(edition.metadata.copies, Some(3));
+        assert_eq!(edition.royalty.get("alice.testnet"), Some(&1_000));
+        assert_eq!(edition.category, Some("cats".to_owned()));
+        assert_eq!(contract.nft_total_supply(), U128(2));
+        assert_eq!(contract.nft_tokens(None, None).len(), 2);
+        assert_eq!(
+            contract
+                .meme_additional_data_by_id
+                .get(&first)
+                .unwrap()
+                .creator_id,
+            "alice.testnet".to_owned()
+        );
+
+        let series = contract.get_series(0).unwrap();
+        assert_eq!(series.minted, 2);
+        assert_eq!(series.supply, 2);
+        assert_eq!(
+            contract
+                .get_series_editions(0, None, None)
+                .into_iter()
+                .map(|edition| edition.token_id)
+                .collect::<Vec<MemeTokenId>>(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "All editions of series are minted")]
+    fn mint_edition_over_max_copies() {
+        let mut contract = series_contract(None);
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+
+        for _ in 0..4 {
+            contract.mint_edition(0, "bob.testnet".to_owned());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Only series creator can mint editions")]
+    fn mint_edition_not_creator() {
+        let mut contract = series_contract(None);
+        testing_env!(get_context("bob.testnet", DEPOSIT));
+
+        contract.mint_edition(0, "bob.testnet".to_owned());
+    }
+
+    #[test]
+    fn buy_edition_pays_creator() {
+        let mut contract = series_contract(Some(PRICE));
+        testing_env!(get_context("bob.testnet", PRICE));
+        contract.storage_deposit(None, None);
+
+        let token_id = contract.buy_edition(0);
+
+        assert_eq!(
+            contract.nft_token(token_id).unwrap().owner_id,
+            "bob.testnet".to_owned()
+        );
+        assert_eq!(
+            get_logs().last().unwrap(),
+            &format!(
+                r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"edition_sold","data":[{{"series_id":0,"token_id":"0:1","creator_id":"alice.testnet","buyer_id":"bob.testnet","price":"{}"}}]}}"#,
+                PRICE
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enought deposit attached")]
+    fn buy_edition_without_deposit() {
+        let mut contract = series_contract(Some(PRICE));
+        testing_env!(get_context("bob.testnet", PRICE - 1));
+
+        contract.buy_edition(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Series is not for sale")]
+    fn buy_edition_not_for_sale() {
+        let mut contract = series_contract(Some(PRICE));
+        testing_env!(get_context("alice.testnet", 1));
+        contract.set_series_price(0, None);
+
+        testing_env!(get_context("bob.testnet", PRICE));
+        contract.buy_edition(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only series creator can change its price")]
+    fn set_series_price_not_creator() {
+        let mut contract = series_contract(Some(PRICE));
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.set_series_price(0, Some(U128(1)));
+    }
+
+    #[test]
+    fn burn_edition_decreases_supply() {
+        let mut contract = series_contract(None);
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        let token_id = contract.mint_edition(0, "bob.testnet".to_owned());
+        testing_env!(get_context("bob.testnet", 1));
+
+        contract.nft_burn(token_id);
+
+        let series = contract.get_series(0).unwrap();
+        assert_eq!(series.minted, 1);
+        assert_eq!(series.supply, 0);
+        assert_eq!(contract.nft_total_supply(), U128(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Meme id cannot contain ':'")]
+    fn nft_mint_with_edition_id() {
+        let mut contract = series_contract(None);
+        contract
+            .roles_per_account
+            .insert(&"alice.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+
+        contract.nft_mint(
+            "0:1".to_owned(),
+            Default::default(),
+            "alice.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use near_sdk::{assert_one_yocto, env, json_types::U128, near_bindgen, AccountId, Promise};
+
+use crate::{
+    contract::StorageManagement,
+    models::{
+        husy::*,
+        storage_balance::{StorageBalance, StorageBalanceBounds},
+    },
+};
+
+#[near_bindgen]
+impl StorageManagement for HusyContract {
+    #[payable]
+    fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let registration_only = registration_only.unwrap_or(false);
+
+        match self.storage_balances.get(&account_id) {
+            Some(total) => {
+                if registration_only {
+                    if amount > 0 {
+                        Promise::new(env::predecessor_account_id()).transfer(amount);
+                    }
+                } else {
+                    self.storage_balances.insert(&account_id, &(total + amount));
+                }
+            }
+            None => {
+                let min_balance = self.storage_balance_min();
+                assert!(
+                    amount >= min_balance,
+                    "The attached deposit is less than the minimum storage balance of {} yoctoNEAR",
+                    min_balance
+                );
+
+                if registration_only {
+                    self.storage_balances.insert(&account_id, &min_balance);
+                    let refund = amount - min_balance;
+                    if refund > 0 {
+                        Promise::new(env::predecessor_account_id()).transfer(refund);
+                    }
+                } else {
+                    self.storage_balances.insert(&account_id, &amount);
+                }
+            }
+        }
+
+        self.get_storage_balance(&account_id).unwrap()
+    }
+
+    #[payable]
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let balance = self
+            .get_storage_balance(&account_id)
+            .unwrap_or_else(|| panic!("Account {} is not registered", account_id));
+
+        let amount = amount.map(|amount| amount.0).unwrap_or(balance.available.0);
+        assert!(
+            amount <= balance.available.0,
+            "Cannot withdraw more than {} yoctoNEAR of available storage balance",
+            balance.available.0
+        );
+
+        self.storage_balances
+            .insert(&account_id, &(balance.total.0 - amount));
+        if amount > 0 {
+            Promise::new(account_id.clone()).transfer(amount);
+        }
+
+        self.get_storage_balance(&account_id).unwrap()
+    }
+
+    /// Storage of memes is paid when they are created, so unregistering
+    /// never affects them. Account holding offers, listings or comments
+    /// can unregister only with `force`, storage released by them later
+    /// is then refunded to account directly
+    #[payable]
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        assert!(
+            force.unwrap_or(false) || !self.has_storage_records(&account_id),
+            "Account still has offers, listings or comments, use force to unregister anyway"
+        );
+
+        match self.storage_balances.remove(&account_id) {
+            Some(total) => {
+                Promise::new(account_id).transfer(total);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128(self.storage_balance_min()),
+            max: None,
+        }
+    }
+
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.get_storage_balance(&account_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, Balance, VMContext};
+
+    use crate::contract::{Comments, ContractInit, Marketplace, MintNFT, Offers};
+    use crate::models::role::Role;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .build()
+    }
+
+    fn min_balance(contract: &HusyContract) -> Balance {
+        contract.storage_balance_bounds().min.0
+    }
+
+    #[test]
+    fn storage_deposit_registers_account() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+        assert!(min > 0);
+        assert_eq!(contract.storage_balance_bounds().max, None);
+
+        testing_env!(get_context("user.testnet", min + 100));
+        let balance = contract.storage_deposit(None, None);
+
+        assert_eq!(
+            balance,
+            StorageBalance {
+                total: U128(min + 100),
+                available: U128(100),
+            }
+        );
+        assert_eq!(
+            contract.storage_balance_of("user.testnet".to_owned()),
+            Some(balance)
+        );
+        assert_eq!(
+            contract.storage_balance_of("other.testnet".to_owned()),
+            None
+        );
+    }
+
+    #[test]
+    fn storage_deposit_for_other_account_tops_up() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+
+        testing_env!(get_context("payer.testnet", min));
+        contract.storage_deposit(Some("user.testnet".to_owned()), None);
+        testing_env!(get_context("payer.testnet", 50));
+        let balance = contract.storage_deposit(Some("user.testnet".to_owned()), None);
+
+        assert_eq!(balance.total, U128(min + 50));
+        assert_eq!(balance.available, U128(50));
+        assert_eq!(
+            contract.storage_balance_of("payer.testnet".to_owned()),
+            None
+        );
+    }
+
+    #[test]
+    fn storage_deposit_registration_only() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+
+        testing_env!(get_context("user.testnet", min + 100));
+        let balance = contract.storage_deposit(None, Some(true));
+        assert_eq!(balance.total, U128(min));
+        assert_eq!(balance.available, U128(0));
+
+        let balance = contract.storage_deposit(None, Some(true));
+        assert_eq!(balance.total, U128(min));
+    }
+
+    #[test]
+    #[should_panic(expected = "The attached deposit is less than the minimum storage balance")]
+    fn storage_deposit_below_minimum() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+
+        testing_env!(get_context("user.testnet", min - 1));
+        contract.storage_deposit(None, None);
+    }
+
+    #[test]
+    fn storage_withdraw_available_balance() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+        testing_env!(get_context("user.testnet", min + 100));
+        contract.storage_deposit(None, None);
+
+        testing_env!(get_context("user.testnet", 1));
+        let balance = contract.storage_withdraw(Some(U128(40)));
+        assert_eq!(balance.available, U128(60));
+
+        let balance = contract.storage_withdraw(None);
+        assert_eq!(balance.total, U128(min));
+        assert_eq!(balance.available, U128(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot withdraw more than 100 yoctoNEAR")]
+    fn storage_withdraw_too_much() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+        testing_env!(get_context("user.testnet", min + 100));
+        contract.storage_deposit(None, None);
+
+        testing_env!(get_context("user.testnet", 1));
+        contract.storage_withdraw(Some(U128(101)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Account user.testnet is not registered")]
+    fn storage_withdraw_not_registered() {
+        testing_env!(get_context("user.testnet", 1));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.storage_withdraw(None);
+    }
+
+    #[test]
+    fn storage_unregister_removes_account() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let min = min_balance(&contract);
+        testing_env!(get_context("user.testnet", min));
+        contract.storage_deposit(None, None);
+
+        testing_env!(get_context("user.testnet", 1));
+        assert!(contract.storage_unregister(None));
+        assert!(!contract.storage_unregister(None));
+        assert_eq!(contract.storage_balance_of("user.testnet".to_owned()), None);
+    }
+
+    /// Registers user and mints meme owned by `owner_id`
+    fn registered_contract(owner_id: &str) -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        testing_env!(get_context("user.testnet", 10u128.pow(24)));
+        contract.storage_deposit(None, None);
+        contract
+            .roles_per_account
+            .insert(&owner_id.to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context(owner_id, 10u128.pow(24)));
+        contract.nft_mint(
+            "meme".to_owned(),
+            Default::default(),
+            owner_id.to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        contract
+    }
+
+    #[test]
+    #[should_panic(expected = "Account still has offers, listings or comments")]
+    fn storage_unregister_with_comment() {
+        let mut contract = registered_contract("owner.testnet");
+        testing_env!(get_context("user.testnet", 0));
+        contract.add_comment("meme".to_owned(), "Nice".to_owned(), None);
+
+        testing_env!(get_context("user.testnet", 1));
+        contract.storage_unregister(None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account still has offers, listings or comments")]
+    fn storage_unregister_with_offer() {
+        let mut contract = registered_contract("owner.testnet");
+        testing_env!(get_context("user.testnet", 1_000));
+        contract.make_offer("meme".to_owned(), 1);
+
+        testing_env!(get_context("user.testnet", 1));
+        contract.storage_unregister(Some(false));
+    }
+
+    #[test]
+    #[should_panic(expected = "Account still has offers, listings or comments")]
+    fn storage_unregister_with_listing() {
+        let mut contract = registered_contract("user.testnet");
+        testing_env!(get_context("user.testnet", 0));
+        contract.list_meme("meme".to_owned(), U128(1_000));
+
+        testing_env!(get_context("user.testnet", 1));
+        contract.storage_unregister(None);
+    }
+
+    #[test]
+    fn storage_unregister_after_delist_and_cancelled_offer() {
+        let mut contract = registered_contract("user.testnet");
+        testing_env!(get_context("user.testnet", 0));
+        contract.list_meme("meme".to_owned(), U128(1_000));
+        testing_env!(get_context("user.testnet", 1));
+        contract.delist("meme".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"owner.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("owner.testnet", 10u128.pow(24)));
+        contract.nft_mint(
+            "dog".to_owned(),
+            Default::default(),
+            "owner.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+        testing_env!(get_context("user.testnet", 1_000));
+        contract.make_offer("dog".to_owned(), 1);
+        testing_env!(get_context("user.testnet", 1));
+        contract.cancel_offer("dog".to_owned());
+
+        assert!(contract.storage_unregister(None));
+    }
+
+    #[test]
+    fn storage_unregister_with_comment_forced() {
+        let mut contract = registered_contract("owner.testnet");
+        testing_env!(get_context("user.testnet", 0));
+        let comment_id = contract.add_comment("meme".to_owned(), "Nice".to_owned(), None);
+
+        testing_env!(get_context("user.testnet", 1));
+        assert!(contract.storage_unregister(Some(true)));
+        assert_eq!(contract.storage_balance_of("user.testnet".to_owned()), None);
+
+        contract.delete_comment("meme".to_owned(), comment_id);
+        assert!(contract
+            .storage_records_per_account
+            .get(&"user.testnet".to_owned())
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn storage_unregister_without_yocto() {
+        testing_env!(get_context("user.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.storage_unregister(None);
+    }
+
+    #[test]
+    fn nft_mint_draws_from_storage_balance() {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let deposit = 10u128.pow(24);
+        testing_env!(get_context("user.testnet", deposit));
+        contract.storage_deposit(None, None);
+
+        contract
+            .roles_per_account
+            .insert(&"user.testnet".to_owned(), &vec![Role::Minter]);
+        testing_env!(get_context("user.testnet", 0));
+        contract.nft_mint(
+            "meme".to_owned(),
+            Default::default(),
+            "user.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+
+        let balance = contract
+            .storage_balance_of("user.testnet".to_owned())
+            .unwrap();
+        assert!(balance.total.0 < deposit);
+        assert!(contract.memes_by_id.get(&"meme".to_owned()).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "or deposit it with storage_deposit to cover storage")]
+    fn nft_mint_without_storage_balance_and_deposit() {
+        testing_env!(get_context("user.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        contract
+            .roles_per_account
+            .insert(&"user.testnet".to_owned(), &vec![Role::Minter]);
+
+        contract.nft_mint(
+            "meme".to_owned(),
+            Default::default(),
+            "user.testnet".to_owned(),
+            None,
+            None,
+            None,
+        );
+    }
+}
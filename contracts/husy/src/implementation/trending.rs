@@ -0,0 +1,173 @@
+use near_sdk::near_bindgen;
+
+use crate::{
+    contract::TrendingFeed,
+    models::{husy::*, meme::MemeTokenView, trending::TrendingWindow},
+};
+
+#[near_bindgen]
+impl TrendingFeed for HusyContract {
+    fn get_trending_memes(
+        &self,
+        window: TrendingWindow,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Vec<MemeTokenView> {
+        let memes = self.get_trending_meme_ids(&window);
+        let limit = limit.unwrap_or(memes.len() as u64);
+
+        memes
+            .into_iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit as usize)
+            .filter_map(|meme_id| self.get_meme_view(meme_id, None))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::ContractInit;
+    use crate::models::meme::{MemeToken, MemeTokenId};
+    use crate::models::trending::TRENDING_BUCKET_DURATION;
+
+    use super::*;
+
+    const START: u64 = 1_000 * TRENDING_BUCKET_DURATION;
+
+    fn get_context(block_timestamp: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id("owner.testnet".try_into().unwrap())
+            .block_timestamp(block_timestamp)
+            .build()
+    }
+
+    fn feed_memes(contract: &mut HusyContract, ids: &[&str]) {
+        for id in ids {
+            let id = id.to_string();
+            contract.memes_by_id.insert(
+                &id,
+                &MemeToken {
+                    owner_id: "owner.testnet".to_owned(),
+                    ..Default::default()
+                },
+            );
+            contract
+                .meme_metadata_by_id
+                .insert(&id, &Default::default());
+            contract
+                .meme_additional_data_by_id
+                .insert(&id, &Default::default());
+        }
+    }
+
+    fn ids(views: Vec<MemeTokenView>) -> Vec<MemeTokenId> {
+        views.into_iter().map(|view| view.token_id).collect()
+    }
+
+    #[test]
+    fn trending_memes_sorted_by_decayed_score() {
+        testing_env!(get_context(START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        feed_memes(&mut contract, &["old", "fresh", "quiet"]);
+        contract.add_trending_likes(&"old".to_owned(), 10);
+        contract.add_trending_likes(&"quiet".to_owned(), 1);
+
+        let half_life = contract.likes_config.get().unwrap().trending_half_life;
+        testing_env!(get_context(START + 2 * half_life));
+        contract.add_trending_likes(&"fresh".to_owned(), 3);
+
+        assert_eq!(
+            ids(contract.get_trending_memes(TrendingWindow::Day, None, None)),
+            vec!["fresh".to_owned(), "old".to_owned(), "quiet".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_trending_memes(TrendingWindow::Hour, None, None)),
+            vec!["fresh".to_owned()]
+        );
+        assert_eq!(
+            ids(contract.get_trending_memes(TrendingWindow::Day, Some(1), Some(1))),
+            vec!["old".to_owned()]
+        );
+    }
+
+    #[test]
+    fn trending_memes_outside_of_window() {
+        testing_env!(get_context(START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        feed_memes(&mut contract, &["meme"]);
+        contract.add_trending_likes(&"meme".to_owned(), 10);
+
+        testing_env!(get_context(START + 24 * TRENDING_BUCKET_DURATION));
+
+        assert!(contract
+            .get_trending_memes(TrendingWindow::Day, None, None)
+            .is_empty());
+        assert_eq!(
+            contract
+                .get_trending_memes(TrendingWindow::Week, None, None)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn old_trending_buckets_are_pruned() {
+        testing_env!(get_context(START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        feed_memes(&mut contract, &["meme"]);
+        contract.add_trending_likes(&"meme".to_owned(), 1);
+
+        testing_env!(get_context(
+            START + TrendingWindow::Week.buckets() * TRENDING_BUCKET_DURATION
+        ));
+        contract.add_trending_likes(&"meme".to_owned(), 1);
+        contract.prune_trending_buckets();
+
+        assert!(contract.trending_buckets.get(&1_000).is_none());
+        assert_eq!(contract.first_trending_bucket, 1_001);
+    }
+
+    #[test]
+    fn trending_buckets_are_pruned_in_batches() {
+        testing_env!(get_context(START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        let meme_ids: Vec<String> = (0..60).map(|index| format!("meme{}", index)).collect();
+        for meme_id in &meme_ids {
+            contract.add_trending_likes(meme_id, 1);
+        }
+
+        testing_env!(get_context(
+            START + (TrendingWindow::Week.buckets() + 1) * TRENDING_BUCKET_DURATION
+        ));
+        contract.prune_trending_buckets();
+        assert_eq!(contract.trending_buckets.get(&1_000).unwrap().len(), 10);
+        assert_eq!(contract.first_trending_bucket, 1_000);
+
+        contract.prune_trending_buckets();
+        assert!(contract.trending_buckets.get(&1_000).is_none());
+        assert_eq!(contract.first_trending_bucket, 1_002);
+    }
+
+    #[test]
+    fn trending_memes_rank_bounded_number_of_newest_entries() {
+        testing_env!(get_context(START));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        for chunk in 0..4 {
+            testing_env!(get_context(START));
+            for index in 0..50 {
+                contract.add_trending_likes(&format!("old{}", chunk * 50 + index), 10);
+            }
+        }
+
+        testing_env!(get_context(START + TRENDING_BUCKET_DURATION));
+        contract.add_trending_likes(&"fresh".to_owned(), 1);
+
+        let meme_ids = contract.get_trending_meme_ids(&TrendingWindow::Day);
+        assert_eq!(meme_ids.len(), 200);
+        assert_eq!(meme_ids.last(), Some(&"fresh".to_owned()));
+    }
+}
@@ -0,0 +1,359 @@
+use near_sdk::{env, near_bindgen, Gas, Promise};
+
+use crate::{
+    contract::Upgradable,
+    models::{
+        husy::*,
+        legacy::HusyContractV0,
+        state_version::{StateVersion, CURRENT_STATE_VERSION, MAX_MIGRATED_MEMES_PER_CALL},
+    },
+    utils::migration::{read_state_version, write_state_version},
+};
+
+const GAS_FOR_UPGRADE: Gas = 20_000_000_000_000;
+const NO_DEPOSIT: u128 = 0;
+
+#[near_bindgen]
+impl Upgradable for HusyContract {
+    /// Deploys contract code passed as raw input and calls `migrate` on it
+    fn upgrade(&self) {
+        self.assert_owner();
+        let code = env::input()
+            .filter(|code| !code.is_empty())
+            .expect("Contract code must be passed as input");
+        let required_gas = env::used_gas() + GAS_FOR_UPGRADE;
+        assert!(
+            env::prepaid_gas() > required_gas,
+            "Attach more than {} gas to upgrade contract",
+            required_gas
+        );
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call(
+                b"migrate".to_vec(),
+                vec![],
+                NO_DEPOSIT,
+                env::prepaid_gas() - required_gas,
+            );
+    }
+
+    #[private]
+    #[init(ignore_state)]
+    fn migrate() -> Self {
+        let contract = match read_state_version() {
+            StateVersion::V0 => {
+                let old: HusyContractV0 = env::state_read().expect("Contract is not initialized");
+                Self::migrate_from_v0(old)
+            }
+            StateVersion::V1 => panic!("Contract state is already up to date"),
+        };
+        write_state_version(&CURRENT_STATE_VERSION);
+
+        contract
+    }
+
+    /// Resumes data migration started by `migrate`. Each call migrates next
+    /// batch of memes and returns count of memes left
+    fn migrate_memes(&mut self, limit: Option<u64>) -> u64 {
+        self.assert_owner();
+        assert!(
+            !self.is_migration_finished(),
+            "There are no memes to migrate"
+        );
+        let limit = limit
+            .unwrap_or(MAX_MIGRATED_MEMES_PER_CALL)
+            .min(MAX_MIGRATED_MEMES_PER_CALL);
+
+        self.migrate_legacy_memes(limit)
+    }
+
+    fn is_migration_finished(&self) -> bool {
+        self.legacy_meme_additional_data.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use near_sdk::borsh::BorshSerialize;
+    use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use crate::contract::{Admin, CategoryRegistry, ContractInit, MemeInteraction};
+    use crate::models::global_likes_data::{GlobalLikesData, LikesCountingMode};
+    use crate::models::husy_metadata::HusyNFTContractMetadata;
+    use crate::models::legacy::MemeAdditionalDataV0;
+    use crate::models::meme::MemeToken;
+    use crate::models::meme_metadata::MemeTokenMetadata;
+    use crate::models::storage::StorageKey;
+    use crate::utils::hashing::hash_account_id;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: &str) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .prepaid_gas(300_000_000_000_000)
+            .build()
+    }
+
+    /// Legacy metadata which does not pass current validation rules
+    fn metadata() -> HusyNFTContractMetadata {
+        HusyNFTContractMetadata {
+            spec: "nft-1.0.0".to_owned(),
+            name: "Husy".to_owned(),
+            symbol: "HUSY".to_owned(),
+            icon: Some("http://husy.io/icon.png".to_owned()),
+            base_uri: Some("http://husy.io".to_owned()),
+            reference: None,
+            reference_hash: None,
+        }
+    }
+
+    fn global_likes_data() -> GlobalLikesData {
+        GlobalLikesData {
+            first_group_sum: 12,
+            first_group_liked_memes: 2,
+            second_group_sum: 0,
+            second_group_liked_memes: 0,
+            last_group_swap_timestamp: 5,
+            likes_counting_mode: LikesCountingMode::FirstGroupActive,
+        }
+    }
+
+    /// Writes state the way contract without state versioning stored it
+    fn write_v0_state() {
+        let mut memes_per_owner = LookupMap::new(StorageKey::MemesPerOwner.try_to_vec().unwrap());
+        let mut memes_by_id = LookupMap::new(StorageKey::MemessById.try_to_vec().unwrap());
+        let mut meme_metadata_by_id =
+            UnorderedMap::new(StorageKey::MemeMetadataById.try_to_vec().unwrap());
+        let mut meme_additional_data_by_id =
+            UnorderedMap::new(StorageKey::MemeAdditionalData.try_to_vec().unwrap());
+
+        let mut owned_memes = UnorderedSet::new(
+            StorageKey::MemePerOwnerInner {
+                account_id_hash: hash_account_id(&"author.testnet".to_owned()),
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+        for (meme_id, data) in [
+            (
+                "cat".to_owned(),
+                MemeAdditionalDataV0 {
+                    likes: 10,
+                    showed_on_main: true,
+                    last_counted_like_timestamp: 5,
+                    category: Some("cats".to_owned()),
+                },
+            ),
+            (
+                "dog".to_owned(),
+                MemeAdditionalDataV0 {
+                    likes: 2,
+                    ..Default::default()
+                },
+            ),
+        ] {
+            owned_memes.insert(&meme_id);
+            memes_by_id.insert(
+                &meme_id,
+                &MemeToken {
+                    owner_id: "author.testnet".to_owned(),
+                    royalty: HashMap::from([("author.testnet".to_owned(), 500)]),
+                    ..Default::default()
+                },
+            );
+            meme_metadata_by_id.insert(
+                &meme_id,
+                &MemeTokenMetadata {
+                    title: Some(meme_id.clone()),
+                    ..Default::default()
+                },
+            );
+            meme_additional_data_by_id.insert(&meme_id, &data);
+        }
+        memes_per_owner.insert(&"author.testnet".to_owned(), &owned_memes);
+
+        env::state_write(&HusyContractV0 {
+            owner_id: "owner.testnet".to_owned(),
+            memes_per_owner,
+            memes_by_id,
+            meme_metadata_by_id,
+            meme_additional_data_by_id,
+            metadata: LazyOption::new(
+                StorageKey::HusyContractMetadata.try_to_vec().unwrap(),
+                Some(&metadata()),
+            ),
+            global_likes_data: LazyOption::new(
+                StorageKey::GlobalLikesData.try_to_vec().unwrap(),
+                Some(&global_likes_data()),
+            ),
+        });
+    }
+
+    #[test]
+    fn migrate_from_v0_state() {
+        testing_env!(get_context("husy.testnet"));
+        write_v0_state();
+
+        let contract = HusyContract::migrate();
+        env::state_write(&contract);
+        let mut contract: HusyContract = env::state_read().unwrap();
+        assert!(contract.is_paused());
+        assert!(!contract.is_migration_finished());
+
+        testing_env!(get_context("owner.testnet"));
+        assert_eq!(contract.migrate_memes(Some(1)), 1);
+        assert_eq!(contract.migrate_memes(None), 0);
+        assert!(contract.is_migration_finished());
+
+        assert_eq!(read_state_version(), CURRENT_STATE_VERSION);
+        assert_eq!(contract.owner_id, "owner.testnet".to_owned());
+        assert_eq!(contract.metadata.get(), Some(metadata()));
+        assert_eq!(contract.global_likes_data.get(), Some(global_likes_data()));
+        assert_eq!(
+            contract
+                .memes_per_owner
+                .get(&"author.testnet".to_owned())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let cat = contract.get_meme_view("cat".to_owned(), None).unwrap();
+        assert_eq!(cat.owner_id, "author.testnet".to_owned());
+        assert_eq!(cat.metadata.title, Some("cat".to_owned()));
+        assert_eq!(cat.royalty.get("author.testnet"), Some(&500));
+        assert_eq!(cat.likes, 10);
+        assert_eq!(cat.category, Some("cats".to_owned()));
+        let cat_data = contract
+            .meme_additional_data_by_id
+            .get(&"cat".to_owned())
+            .unwrap();
+        assert_eq!(cat_data.creator_id, "author.testnet".to_owned());
+        assert_eq!(cat_data.counted_likes, 10);
+        assert_eq!(
+            contract
+                .meme_additional_data_by_id
+                .get(&"dog".to_owned())
+                .unwrap()
+                .counted_likes,
+            0
+        );
+
+        assert_eq!(
+            contract
+                .get_category("cats".to_owned())
+                .unwrap()
+                .memes_count,
+            1
+        );
+        assert_eq!(
+            contract
+                .get_memes(None, None, Some("cats".to_owned()), None, true)
+                .len(),
+            1
+        );
+        assert_eq!(contract.get_memes(None, None, None, None, false).len(), 2);
+        assert!(contract.likes_config.get().is_some());
+        assert!(contract.like_revenue_split.get().is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Memes are not migrated yet")]
+    fn unpause_before_memes_are_migrated() {
+        testing_env!(get_context("husy.testnet"));
+        write_v0_state();
+        let mut contract = HusyContract::migrate();
+
+        let mut context = get_context("owner.testnet");
+        context.attached_deposit = 1;
+        testing_env!(context);
+        contract.migrate_memes(Some(1));
+        contract.unpause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn migrate_memes_by_not_owner() {
+        testing_env!(get_context("husy.testnet"));
+        write_v0_state();
+        let mut contract = HusyContract::migrate();
+
+        testing_env!(get_context("user.testnet"));
+        contract.migrate_memes(None);
+    }
+
+    #[test]
+    #[should_panic(expected = "There are no memes to migrate")]
+    fn migrate_memes_of_current_state() {
+        testing_env!(get_context("owner.testnet"));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.migrate_memes(None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract state is already up to date")]
+    fn migrate_current_state() {
+        testing_env!(get_context("husy.testnet"));
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+        env::state_write(&contract);
+
+        HusyContract::migrate();
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is not initialized")]
+    fn migrate_without_state() {
+        testing_env!(get_context("husy.testnet"));
+
+        HusyContract::migrate();
+    }
+
+    #[test]
+    fn upgrade_by_owner() {
+        let mut context = get_context("owner.testnet");
+        context.input = vec![0, 97, 115, 109];
+        testing_env!(context);
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.upgrade();
+    }
+
+    #[test]
+    #[should_panic(expected = "Only contract owner can call this method")]
+    fn upgrade_by_not_owner() {
+        let mut context = get_context("user.testnet");
+        context.input = vec![0, 97, 115, 109];
+        testing_env!(context);
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.upgrade();
+    }
+
+    #[test]
+    #[should_panic(expected = "to upgrade contract")]
+    fn upgrade_without_enough_gas() {
+        let mut context = get_context("owner.testnet");
+        context.input = vec![0, 97, 115, 109];
+        context.prepaid_gas = GAS_FOR_UPGRADE;
+        testing_env!(context);
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.upgrade();
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract code must be passed as input")]
+    fn upgrade_without_code() {
+        testing_env!(get_context("owner.testnet"));
+        let contract = HusyContract::new_default("owner.testnet".to_owned());
+
+        contract.upgrade();
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+
+use near_sdk::{
+    assert_one_yocto, env,
+    json_types::{Base58PublicKey, Base64VecU8},
+    near_bindgen, AccountId, Promise,
+};
+
+use crate::{
+    contract::LazyMinting,
+    events::{HusyEvent, VoucherRedeemedLog},
+    models::{
+        husy::*, meme::MemeTokenId, meme_metadata::MemeTokenMetadata,
+        provenance::PROVENANCE_STORAGE_RESERVE, voucher::Voucher,
+    },
+    utils::{
+        mint_helpers::{assert_valid_meme_id, assert_valid_royalty},
+        voucher_helpers::{
+            assert_ed25519_key, assert_valid_signature, hash_metadata, hash_voucher_message,
+            voucher_message,
+        },
+    },
+};
+
+#[near_bindgen]
+impl LazyMinting for HusyContract {
+    /// Replacing key invalidates all vouchers signed with the previous one
+    #[payable]
+    fn register_creator_key(&mut self, public_key: Base58PublicKey) {
+        assert_ed25519_key(&public_key);
+
+        let initial_storage_usage = env::storage_usage();
+        self.creator_keys
+            .insert(&env::predecessor_account_id(), &public_key);
+        self.pay_for_storage_and_refund(initial_storage_usage);
+    }
+
+    #[payable]
+    fn remove_creator_key(&mut self) {
+        assert_one_yocto();
+
+        let initial_storage_usage = env::storage_usage();
+        self.creator_keys
+            .remove(&env::predecessor_account_id())
+            .expect("Creator has no registered public key");
+        self.pay_for_storage_and_refund(initial_storage_usage);
+    }
+
+    fn get_creator_key(&self, account_id: AccountId) -> Option<Base58PublicKey> {
+        self.creator_keys.get(&account_id)
+    }
+
+    /// Mints meme to buyer and pays voucher price to creator,
+    /// storage of meme is paid by buyer
+    #[payable]
+    fn redeem_voucher(
+        &mut self,
+        voucher: Voucher,
+        metadata: MemeTokenMetadata,
+        signature: Base64VecU8,
+    ) -> MemeTokenId {
+        self.assert_not_paused();
+        assert_valid_meme_id(&voucher.token_id);
+        self.assert_valid_meme_metadata(&metadata);
+        let royalty = match voucher.royalty {
+            0 => HashMap::new(),
+            royalty => HashMap::from([(voucher.creator_id.clone(), royalty)]),
+        };
+        assert_valid_royalty(&royalty);
+        assert!(
+            voucher.expires_at > env::block_timestamp(),
+            "Voucher has expired"
+        );
+        assert_eq!(
+            hash_metadata(&metadata),
+            voucher.metadata_hash,
+            "Metadata does not match voucher"
+        );
+        let public_key = self
+            .creator_keys
+            .get(&voucher.creator_id)
+            .expect("Creator has no registered public key");
+        let message = voucher_message(&voucher);
+        assert_valid_signature(&public_key, &message, &signature);
+
+        let price = voucher.price.0;
+        let attached = env::attached_deposit();
+        assert!(
+            attached >= price,
+            "Not enought deposit attached. You need at least: {} yoctoNEAR",
+            price
+        );
+
+        let buyer_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
+        assert!(
+            self.redeemed_vouchers
+                .insert(&hash_voucher_message(&message)),
+            "Voucher has already been redeemed"
+        );
+        self.internal_mint(
+            &voucher.token_id,
+            Some(&metadata),
+            buyer_id.clone(),
+            royalty,
+            None,
+            voucher.creator_id.clone(),
+        );
+        self.mark_meme_sold(&voucher.token_id, price);
+        let refund = self.pay_for_storage(
+            &buyer_id,
+            initial_storage_usage - PROVENANCE_STORAGE_RESERVE,
+            attached - price,
+        );
+        if price > 0 {
+            Promise::new(voucher.creator_id.clone()).transfer(price);
+        }
+
+        HusyEvent::VoucherRedeemed(vec![VoucherRedeemedLog {
+            token_id: voucher.token_id.clone(),
+            creator_id: voucher.creator_id,
+            buyer_id: buyer_id.clone(),
+            price: voucher.price,
+        }])
+        .emit();
+
+        if refund > 0 {
+            Promise::new(buyer_id).transfer(refund);
+        }
+
+        voucher.token_id
+    }
+
+    fn is_voucher_redeemed(&self, voucher: Voucher) -> bool {
+        self.redeemed_vouchers
+            .contains(&hash_voucher_message(&voucher_message(&voucher)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+    use near_sdk::json_types::U128;
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{
+        test_utils::{get_logs, VMContextBuilder},
+        testing_env, Balance, VMContext,
+    };
+
+    use crate::contract::{ContractInit, NFTBurn, NFTTokenCore};
+
+    use super::*;
+
+    const NOW: u64 = 1_000;
+    const PRICE: Balance = 1_000_000_000_000_000_000_000_000;
+    const DEPOSIT: Balance = 100_000_000_000_000_000_000_000;
+
+    fn get_context(predecessor_account_id: &str, attached: Balance) -> VMContext {
+        VMContextBuilder::new()
+            .current_account_id("husy.testnet".try_into().unwrap())
+            .predecessor_account_id(predecessor_account_id.try_into().unwrap())
+            .attached_deposit(attached)
+            .block_timestamp(NOW)
+            .build()
+    }
+
+    fn keypair(seed: u8) -> Keypair {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public: PublicKey = (&secret).into();
+        Keypair { secret, public }
+    }
+
+    fn near_public_key(keypair: &Keypair) -> Base58PublicKey {
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(keypair.public.as_bytes());
+        Base58PublicKey(bytes)
+    }
+
+    fn metadata() -> MemeTokenMetadata {
+        MemeTokenMetadata {
+            title: Some("Lazy cat".to_owned()),
+            media: Some("ipfs://cat.png".to_owned()),
+            media_hash: Some(Base64VecU8(vec![1; 32])),
+            ..Default::default()
+        }
+    }
+
+    fn voucher(token_id: &str) -> Voucher {
+        Voucher {
+            creator_id: "alice.testnet".to_owned(),
+            token_id: token_id.to_owned(),
+            metadata_hash: hash_metadata(&metadata()),
+            price: U128(PRICE),
+            royalty: 1_000,
+            expires_at: NOW + 1,
+        }
+    }
+
+    fn sign(keypair: &Keypair, voucher: &Voucher) -> Base64VecU8 {
+        Base64VecU8(keypair.sign(&voucher_message(voucher)).to_bytes().to_vec())
+    }
+
+    fn voucher_contract() -> HusyContract {
+        testing_env!(get_context("owner.testnet", 0));
+        let mut contract = HusyContract::new_default("owner.testnet".to_owned());
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        contract.register_creator_key(near_public_key(&keypair(1)));
+
+        contract
+    }
+
+    fn redeem(contract: &mut HusyContract, voucher: Voucher, signature: Base64VecU8) {
+        testing_env!(get_context("bob.testnet", PRICE + DEPOSIT));
+        contract.redeem_voucher(voucher, metadata(), signature);
+    }
+
+    #[test]
+    fn redeem_voucher_mints_meme() {
+        let mut contract = voucher_contract();
+        let voucher = voucher("cat");
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+
+        let meme = contract.nft_token("cat".to_owned()).unwrap();
+        assert_eq!(meme.owner_id, "bob.testnet".to_owned());
+        assert_eq!(meme.metadata, metadata());
+        assert_eq!(
+            contract
+                .meme_additional_data_by_id
+                .get(&"cat".to_owned())
+                .unwrap()
+                .creator_id,
+            "alice.testnet".to_owned()
+        );
+        assert_eq!(meme.royalty.get("alice.testnet"), Some(&1_000));
+        assert!(contract.sold_memes.contains(&"cat".to_owned()));
+        assert!(contract.is_voucher_redeemed(voucher));
+        assert!(!contract.is_voucher_redeemed(self::voucher("dog")));
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[{"owner_id":"bob.testnet","token_ids":["cat"]}]}"#.to_owned(),
+                format!(
+                    r#"EVENT_JSON:{{"standard":"husy","version":"1.0.0","event":"voucher_redeemed","data":[{{"token_id":"cat","creator_id":"alice.testnet","buyer_id":"bob.testnet","price":"{}"}}]}}"#,
+                    PRICE
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher has already been redeemed")]
+    fn redeem_voucher_after_burn() {
+        let mut contract = voucher_contract();
+        let voucher = voucher("cat");
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+        testing_env!(get_context("bob.testnet", 1));
+        contract.nft_burn("cat".to_owned());
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher signature is invalid")]
+    fn redeem_voucher_signed_by_other_key() {
+        let mut contract = voucher_contract();
+        let voucher = voucher("cat");
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(2), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher signature is invalid")]
+    fn redeem_tampered_voucher() {
+        let mut contract = voucher_contract();
+        let signature = sign(&keypair(1), &voucher("cat"));
+        let mut voucher = voucher("cat");
+        voucher.price = U128(1);
+
+        redeem(&mut contract, voucher, signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "Metadata does not match voucher")]
+    fn redeem_voucher_with_other_metadata() {
+        let mut contract = voucher_contract();
+        let voucher = voucher("cat");
+        testing_env!(get_context("bob.testnet", PRICE + DEPOSIT));
+
+        contract.redeem_voucher(
+            voucher.clone(),
+            Default::default(),
+            sign(&keypair(1), &voucher),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Sum of royalities cannot be bigger than 10 000")]
+    fn redeem_voucher_with_too_high_royalty() {
+        let mut contract = voucher_contract();
+        let mut voucher = voucher("cat");
+        voucher.royalty = 10_000;
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher has expired")]
+    fn redeem_expired_voucher() {
+        let mut contract = voucher_contract();
+        let mut voucher = voucher("cat");
+        voucher.expires_at = NOW;
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enought deposit attached")]
+    fn redeem_voucher_without_price() {
+        let mut contract = voucher_contract();
+        let voucher = voucher("cat");
+        testing_env!(get_context("bob.testnet", PRICE - 1));
+
+        contract.redeem_voucher(voucher.clone(), metadata(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Creator has no registered public key")]
+    fn redeem_voucher_after_key_removal() {
+        let mut contract = voucher_contract();
+        testing_env!(get_context("alice.testnet", 1));
+        contract.remove_creator_key();
+        assert_eq!(contract.get_creator_key("alice.testnet".to_owned()), None);
+        let voucher = voucher("cat");
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher signature is invalid")]
+    fn redeem_voucher_after_key_rotation() {
+        let mut contract = voucher_contract();
+        testing_env!(get_context("alice.testnet", DEPOSIT));
+        contract.register_creator_key(near_public_key(&keypair(2)));
+        assert_eq!(
+            contract.get_creator_key("alice.testnet".to_owned()),
+            Some(near_public_key(&keypair(2)))
+        );
+        let voucher = voucher("cat");
+
+        redeem(&mut contract, voucher.clone(), sign(&keypair(1), &voucher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only ed25519 public keys are supported")]
+    fn register_secp256k1_key() {
+        let mut contract = voucher_contract();
+        let mut key = vec![1];
+        key.extend_from_slice(&[0; 64]);
+
+        contract.register_creator_key(Base58PublicKey(key));
+    }
+}
@@ -1,4 +1,5 @@
 mod contract;
+mod events;
 mod ext_contracts;
 mod ext_self_contracts;
 mod implementation;
@@ -0,0 +1,45 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Balance,
+};
+
+use super::meme::MemeTokenId;
+
+/// Bids placed this close to the end of auction extend it
+pub const ANTI_SNIPING_WINDOW: u64 = 600_000_000_000;
+/// Time left for other bidders after late bid
+pub const ANTI_SNIPING_EXTENSION: u64 = 600_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Bid {
+    pub bidder_id: AccountId,
+    pub amount: Balance,
+    pub placed_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Auction {
+    pub seller_id: AccountId,
+    pub reserve_price: Balance,
+    pub min_increment: Balance,
+    pub started_at: u64,
+    pub ends_at: u64,
+    pub highest_bid: Option<Bid>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuctionView {
+    pub token_id: MemeTokenId,
+    pub seller_id: AccountId,
+    pub reserve_price: U128,
+    pub min_increment: U128,
+    pub started_at: u64,
+    pub ends_at: u64,
+    pub highest_bidder_id: Option<AccountId>,
+    pub highest_bid: Option<U128>,
+    /// Smallest amount which next bid has to attach
+    pub min_next_bid: U128,
+}
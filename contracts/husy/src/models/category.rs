@@ -0,0 +1,22 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+pub type CategoryId = String;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Default, Debug)]
+pub struct Category {
+    pub name: String,
+    pub retired: bool,
+    pub memes_count: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CategoryView {
+    pub id: CategoryId,
+    pub name: String,
+    pub retired: bool,
+    pub memes_count: u64,
+}
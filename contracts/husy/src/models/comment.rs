@@ -0,0 +1,33 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+use super::meme::MemeTokenId;
+
+pub type CommentId = u64;
+
+pub const MAX_COMMENT_LENGTH: usize = 500;
+/// Upper bound of `limit` in `remove_burned_meme_comments`
+pub const MAX_COMMENTS_REMOVED_PER_CALL: u64 = 100;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Comment {
+    pub author_id: AccountId,
+    pub text: String,
+    pub created_at: u64,
+    /// Comment this one replies to, replies cannot be replied to
+    pub parent_id: Option<CommentId>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CommentView {
+    pub id: CommentId,
+    pub meme_id: MemeTokenId,
+    pub author_id: AccountId,
+    pub text: String,
+    pub created_at: u64,
+    pub parent_id: Option<CommentId>,
+}
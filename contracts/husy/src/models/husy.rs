@@ -1,15 +1,31 @@
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
-    collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet},
-    near_bindgen, AccountId, PanicOnDefault,
+    collections::{LazyOption, LookupMap, LookupSet, UnorderedMap, UnorderedSet},
+    json_types::Base58PublicKey,
+    near_bindgen, AccountId, Balance, CryptoHash, PanicOnDefault, StorageUsage,
 };
 
 use super::{
+    auction::Auction,
+    category::{Category, CategoryId},
+    comment::{Comment, CommentId},
     global_likes_data::GlobalLikesData,
     husy_metadata::HusyNFTContractMetadata,
+    legacy::MemeAdditionalDataV0,
+    like_revenue_split::LikeRevenueSplit,
+    likes_config::LikesConfig,
+    listing::Listing,
     meme::{MemeToken, MemeTokenId},
     meme_additional_data::MemeAdditionalData,
+    meme_like::MemeLike,
     meme_metadata::MemeTokenMetadata,
+    metadata_update::MetadataRevision,
+    moderation::{Report, ReportId},
+    offer::Offer,
+    provenance::ProvenanceEntry,
+    role::Role,
+    series::{Series, SeriesId},
+    trending::TrendingScore,
 };
 
 #[near_bindgen]
@@ -22,4 +38,51 @@ pub struct HusyContract {
     pub meme_additional_data_by_id: UnorderedMap<MemeTokenId, MemeAdditionalData>,
     pub metadata: LazyOption<HusyNFTContractMetadata>,
     pub global_likes_data: LazyOption<GlobalLikesData>,
+    pub likes_per_account: LookupMap<AccountId, UnorderedMap<MemeTokenId, MemeLike>>,
+    pub likers_per_meme: LookupMap<MemeTokenId, UnorderedSet<AccountId>>,
+    pub like_revenue_split: LazyOption<LikeRevenueSplit>,
+    pub treasury_balance: Balance,
+    pub reward_pool_balance: Balance,
+    pub likes_config: LazyOption<LikesConfig>,
+    pub pending_owner_id: Option<AccountId>,
+    pub paused: bool,
+    pub roles_per_account: UnorderedMap<AccountId, Vec<Role>>,
+    pub categories: UnorderedMap<CategoryId, Category>,
+    pub memes_per_category: LookupMap<CategoryId, UnorderedSet<MemeTokenId>>,
+    pub main_page_memes: UnorderedSet<MemeTokenId>,
+    pub main_page_memes_per_category: LookupMap<CategoryId, UnorderedSet<MemeTokenId>>,
+    pub trending_scores: LookupMap<MemeTokenId, TrendingScore>,
+    pub trending_buckets: LookupMap<u64, UnorderedSet<MemeTokenId>>,
+    pub first_trending_bucket: u64,
+    pub storage_balances: LookupMap<AccountId, Balance>,
+    pub account_storage_usage: StorageUsage,
+    pub listings: UnorderedMap<MemeTokenId, Listing>,
+    pub auctions: UnorderedMap<MemeTokenId, Auction>,
+    pub offers_per_meme: LookupMap<MemeTokenId, UnorderedMap<AccountId, Offer>>,
+    pub offers_per_bidder: LookupMap<AccountId, UnorderedSet<MemeTokenId>>,
+    pub reports: UnorderedMap<ReportId, Report>,
+    pub reports_per_meme: LookupMap<MemeTokenId, UnorderedMap<AccountId, ReportId>>,
+    pub next_report_id: ReportId,
+    pub hidden_memes: UnorderedSet<MemeTokenId>,
+    pub frozen_memes: UnorderedSet<MemeTokenId>,
+    pub comments_per_meme: LookupMap<MemeTokenId, UnorderedMap<CommentId, Comment>>,
+    pub next_comment_id: CommentId,
+    pub remix_royalty: u32,
+    pub remix_parents: LookupMap<MemeTokenId, MemeTokenId>,
+    pub remixes_per_meme: LookupMap<MemeTokenId, UnorderedSet<MemeTokenId>>,
+    pub series: UnorderedMap<SeriesId, Series>,
+    pub next_series_id: SeriesId,
+    pub editions_per_series: LookupMap<SeriesId, UnorderedSet<MemeTokenId>>,
+    pub creator_keys: LookupMap<AccountId, Base58PublicKey>,
+    pub redeemed_vouchers: LookupSet<CryptoHash>,
+    pub sold_memes: LookupSet<MemeTokenId>,
+    pub metadata_revisions: LookupMap<MemeTokenId, Vec<MetadataRevision>>,
+    pub resolve_relative_uris: bool,
+    pub memes_per_creator: LookupMap<AccountId, UnorderedSet<MemeTokenId>>,
+    pub provenance_per_meme: LookupMap<MemeTokenId, Vec<ProvenanceEntry>>,
+    pub storage_records_per_account: LookupMap<AccountId, u64>,
+    pub owner_feeds: LookupMap<CryptoHash, UnorderedSet<MemeTokenId>>,
+    /// `V0` meme additional data which is not migrated yet
+    pub legacy_meme_additional_data: Option<UnorderedMap<MemeTokenId, MemeAdditionalDataV0>>,
+    pub listings_per_category: LookupMap<CategoryId, UnorderedSet<MemeTokenId>>,
 }
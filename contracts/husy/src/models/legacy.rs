@@ -0,0 +1,33 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet},
+    AccountId,
+};
+
+use super::{
+    global_likes_data::GlobalLikesData,
+    husy_metadata::HusyNFTContractMetadata,
+    meme::{MemeToken, MemeTokenId},
+    meme_metadata::MemeTokenMetadata,
+};
+
+/// Contract state layout of `StateVersion::V0`
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HusyContractV0 {
+    pub owner_id: AccountId,
+    pub memes_per_owner: LookupMap<AccountId, UnorderedSet<MemeTokenId>>,
+    pub memes_by_id: LookupMap<MemeTokenId, MemeToken>,
+    pub meme_metadata_by_id: UnorderedMap<MemeTokenId, MemeTokenMetadata>,
+    pub meme_additional_data_by_id: UnorderedMap<MemeTokenId, MemeAdditionalDataV0>,
+    pub metadata: LazyOption<HusyNFTContractMetadata>,
+    pub global_likes_data: LazyOption<GlobalLikesData>,
+}
+
+/// Meme additional data layout of `StateVersion::V0`
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Default, Debug)]
+pub struct MemeAdditionalDataV0 {
+    pub likes: u64,
+    pub showed_on_main: bool,
+    pub last_counted_like_timestamp: u64,
+    pub category: Option<String>,
+}
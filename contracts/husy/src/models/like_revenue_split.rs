@@ -0,0 +1,23 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Shares of a like payment in basis points (1/100 of percent), must sum up to 10 000
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LikeRevenueSplit {
+    pub creator_share: u32,
+    pub treasury_share: u32,
+    pub reward_pool_share: u32,
+}
+
+impl LikeRevenueSplit {
+    pub fn new() -> Self {
+        LikeRevenueSplit {
+            creator_share: 7_000,
+            treasury_share: 2_000,
+            reward_pool_share: 1_000,
+        }
+    }
+}
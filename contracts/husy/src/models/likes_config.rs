@@ -0,0 +1,36 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+};
+
+pub const DEFAULT_YOCTO_NEAR_PER_LIKE: u128 = 50_000_000_000_000_000_000_000;
+pub const DEFAULT_MAIN_AVERAGE_FACTOR: u32 = 8_000;
+pub const DEFAULT_MIN_LIKES: u64 = 3;
+pub const DEFAULT_MIN_TIME_TO_SWITCH_MODE: u64 = 604_800_000_000_000;
+pub const DEFAULT_TRENDING_HALF_LIFE: u64 = 21_600_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LikesConfig {
+    pub yocto_near_per_like: U128,
+    /// Part of current likes average (in basis points) needed to get on main page
+    pub main_average_factor: u32,
+    pub min_likes: u64,
+    /// Nanoseconds between swapping likes counting groups
+    pub min_time_to_switch_mode: u64,
+    /// Nanoseconds after which trending score of meme drops by half
+    pub trending_half_life: u64,
+}
+
+impl LikesConfig {
+    pub fn new() -> Self {
+        LikesConfig {
+            yocto_near_per_like: U128(DEFAULT_YOCTO_NEAR_PER_LIKE),
+            main_average_factor: DEFAULT_MAIN_AVERAGE_FACTOR,
+            min_likes: DEFAULT_MIN_LIKES,
+            min_time_to_switch_mode: DEFAULT_MIN_TIME_TO_SWITCH_MODE,
+            trending_half_life: DEFAULT_TRENDING_HALF_LIFE,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Balance,
+};
+
+use super::{category::CategoryId, meme::MemeTokenId};
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Listing {
+    /// Owner at the time of listing, listing is void once meme changes hands
+    pub seller_id: AccountId,
+    pub price: Balance,
+    pub listed_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ListingView {
+    pub token_id: MemeTokenId,
+    pub owner_id: AccountId,
+    pub price: U128,
+    pub listed_at: u64,
+    pub category: Option<CategoryId>,
+}
@@ -29,4 +29,7 @@ pub struct MemeTokenView {
     pub likes: u64,
     pub showed_on_main: bool,
     pub category: Option<String>,
+    pub comments: u64,
+    pub creator_id: AccountId,
+    pub minted_at: u64,
 }
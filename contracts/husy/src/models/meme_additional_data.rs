@@ -1,9 +1,18 @@
-use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    AccountId,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Default, Debug)]
 pub struct MemeAdditionalData {
     pub likes: u64,
     pub showed_on_main: bool,
     pub last_counted_like_timestamp: u64,
+    /// Likes added to global likes data since last counted group swap
+    pub counted_likes: u64,
     pub category: Option<String>,
+    pub category_changed: bool,
+    pub creator_id: AccountId,
+    /// Block timestamp of mint, 0 for memes minted before it was recorded
+    pub minted_at: u64,
 }
@@ -0,0 +1,27 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+use super::meme::MemeTokenId;
+
+/// Likers whose ledger entries one call removes after meme is burned
+pub const MAX_LIKERS_REMOVED_PER_CALL: u64 = 100;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Default, Debug)]
+pub struct MemeLike {
+    pub likes: u64,
+    pub first_liked_at: u64,
+    pub last_liked_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeLikeView {
+    pub meme_id: MemeTokenId,
+    pub account_id: AccountId,
+    pub likes: u64,
+    pub first_liked_at: u64,
+    pub last_liked_at: u64,
+}
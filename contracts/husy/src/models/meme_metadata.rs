@@ -4,7 +4,9 @@ use near_sdk::{
     serde::{Deserialize, Serialize},
 };
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Default, Debug, Clone,
+)]
 #[serde(crate = "near_sdk::serde")]
 pub struct MemeTokenMetadata {
     pub title: Option<String>,
@@ -20,3 +22,10 @@ pub struct MemeTokenMetadata {
     pub reference: Option<String>,
     pub reference_hash: Option<Base64VecU8>,
 }
+
+pub const MAX_TITLE_LENGTH: usize = 200;
+pub const MAX_DESCRIPTION_LENGTH: usize = 2_000;
+pub const MAX_EXTRA_LENGTH: usize = 4_096;
+/// Length of sha256 hash required for `media_hash` and `reference_hash`
+pub const METADATA_HASH_LENGTH: usize = 32;
+pub const ALLOWED_URI_SCHEMES: [&str; 3] = ["ar://", "ipfs://", "https://"];
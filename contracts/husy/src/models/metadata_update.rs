@@ -0,0 +1,29 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::Base64VecU8,
+    serde::{Deserialize, Serialize},
+};
+
+use super::meme_metadata::MemeTokenMetadata;
+
+/// Oldest revisions are dropped once limit is reached
+pub const MAX_METADATA_REVISIONS: usize = 10;
+
+/// Fields left as `None` are kept unchanged
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeMetadataPatch {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<Base64VecU8>,
+    pub extra: Option<String>,
+}
+
+/// Metadata as it was before being replaced by an update
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MetadataRevision {
+    pub metadata: MemeTokenMetadata,
+    pub replaced_at: u64,
+}
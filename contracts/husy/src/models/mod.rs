@@ -1,8 +1,27 @@
+pub mod auction;
+pub mod category;
+pub mod comment;
 pub mod global_likes_data;
 pub mod husy;
 pub mod husy_metadata;
+pub mod legacy;
+pub mod like_revenue_split;
+pub mod likes_config;
+pub mod listing;
 pub mod meme;
 pub mod meme_additional_data;
+pub mod meme_like;
 pub mod meme_metadata;
+pub mod metadata_update;
+pub mod moderation;
+pub mod offer;
 pub mod payout;
+pub mod provenance;
+pub mod remix;
+pub mod role;
+pub mod series;
+pub mod state_version;
 pub mod storage;
+pub mod storage_balance;
+pub mod trending;
+pub mod voucher;
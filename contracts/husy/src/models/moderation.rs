@@ -0,0 +1,45 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Balance,
+};
+
+use super::meme::MemeTokenId;
+
+pub type ReportId = u64;
+
+/// 0.01 NEAR, covers storage of report with reason of maximal length
+pub const REPORT_DEPOSIT: Balance = 10_000_000_000_000_000_000_000;
+pub const MAX_REPORT_REASON_LENGTH: usize = 280;
+/// All open reports of meme are resolved in one call, so their number is limited
+pub const MAX_REPORTS_PER_MEME: u64 = 50;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Report {
+    pub token_id: MemeTokenId,
+    pub reporter_id: AccountId,
+    pub reason: String,
+    pub deposit: Balance,
+    pub reported_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReportView {
+    pub id: ReportId,
+    pub token_id: MemeTokenId,
+    pub reporter_id: AccountId,
+    pub reason: String,
+    pub deposit: U128,
+    pub reported_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemeModerationView {
+    pub token_id: MemeTokenId,
+    pub hidden: bool,
+    pub frozen: bool,
+    pub reports: u64,
+}
@@ -0,0 +1,34 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Balance,
+};
+
+use super::meme::MemeTokenId;
+
+/// Offers one meme can hold at once, bounds refunds done when meme is burned
+pub const MAX_OFFERS_PER_MEME: u64 = 50;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Offer {
+    pub amount: Balance,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+impl Offer {
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OfferView {
+    pub token_id: MemeTokenId,
+    pub bidder_id: AccountId,
+    pub amount: U128,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
@@ -0,0 +1,41 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, StorageUsage,
+};
+
+/// Once log is full, oldest entries are dropped but mint entry is always kept
+pub const MAX_PROVENANCE_ENTRIES: usize = 32;
+
+/// Upper bound of storage taken by one entry, both accounts having maximum length
+const MAX_PROVENANCE_ENTRY_SIZE: StorageUsage = 164;
+
+/// Storage of entries following the mint entry. Minter pays for it upfront,
+/// so contract does not cover storage of later transfers
+pub const PROVENANCE_STORAGE_RESERVE: StorageUsage =
+    (MAX_PROVENANCE_ENTRIES as StorageUsage - 1) * MAX_PROVENANCE_ENTRY_SIZE;
+
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Copy, Debug,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceEvent {
+    Mint,
+    Transfer,
+    Sale,
+    /// Receiver of `nft_transfer_call` returned the meme
+    Revert,
+    Burn,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProvenanceEntry {
+    pub event: ProvenanceEvent,
+    pub from_id: Option<AccountId>,
+    pub to_id: Option<AccountId>,
+    pub price: Option<U128>,
+    pub timestamp: u64,
+}
@@ -0,0 +1,3 @@
+/// Royalty in basis points added for creator of remixed meme
+pub const DEFAULT_REMIX_ROYALTY: u32 = 500;
+pub const MAX_REMIX_ROYALTY: u32 = 5_000;
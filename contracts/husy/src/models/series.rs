@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Balance,
+};
+
+use super::{category::CategoryId, meme_metadata::MemeTokenMetadata};
+
+pub type SeriesId = u64;
+
+/// Separates series id and edition number in token id of edition
+pub const EDITION_DELIMITER: char = ':';
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub struct Series {
+    pub creator_id: AccountId,
+    /// Metadata shared by all editions
+    pub metadata: MemeTokenMetadata,
+    pub royalty: HashMap<AccountId, u32>,
+    pub category: Option<CategoryId>,
+    pub max_copies: u64,
+    /// Editions minted so far, burned ones included
+    pub minted: u64,
+    /// Price for public minting, series is not for sale without it
+    pub price: Option<Balance>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeriesView {
+    pub series_id: SeriesId,
+    pub creator_id: AccountId,
+    pub metadata: MemeTokenMetadata,
+    pub royalty: HashMap<AccountId, u32>,
+    pub category: Option<CategoryId>,
+    pub max_copies: u64,
+    pub minted: u64,
+    /// Editions that were not burned
+    pub supply: u64,
+    pub price: Option<U128>,
+}
@@ -0,0 +1,20 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+/// Storage key of the version tag kept next to the contract state
+pub const STATE_VERSION_KEY: &[u8] = b"STATE_VERSION";
+
+/// Every layout contract state was stored in. State written before
+/// versioning was introduced has no version tag and is treated as `V0`
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Debug)]
+pub enum StateVersion {
+    V0,
+    V1,
+}
+
+/// Layout changes made after `V1` was introduced ship together with it
+/// as one release, so no intermediate layout was ever deployed. Any later
+/// change of `HusyContract` fields needs new version and migration
+pub const CURRENT_STATE_VERSION: StateVersion = StateVersion::V1;
+
+/// Upper bound of `limit` in `migrate_memes`
+pub const MAX_MIGRATED_MEMES_PER_CALL: u64 = 100;
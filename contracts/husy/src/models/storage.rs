@@ -12,4 +12,53 @@ pub enum StorageKey {
     GlobalLikesData,
     MemeAdditionalData,
     MemePerOwnerInner { account_id_hash: CryptoHash },
+    LikesPerAccount,
+    LikesPerAccountInner { account_id_hash: CryptoHash },
+    LikersPerMeme,
+    LikersPerMemeInner { meme_id_hash: CryptoHash },
+    LikeRevenueSplit,
+    LikesConfig,
+    RolesPerAccount,
+    Categories,
+    MemesPerCategory,
+    MemesPerCategoryInner { category_id_hash: CryptoHash },
+    MainPageMemes,
+    MainPageMemesPerCategory,
+    MainPageMemesPerCategoryInner { category_id_hash: CryptoHash },
+    TrendingScores,
+    TrendingBuckets,
+    TrendingBucketInner { bucket: u64 },
+    StorageBalances,
+    Listings,
+    Auctions,
+    OffersPerMeme,
+    OffersPerMemeInner { meme_id_hash: CryptoHash },
+    OffersPerBidder,
+    OffersPerBidderInner { account_id_hash: CryptoHash },
+    Reports,
+    ReportsPerMeme,
+    ReportsPerMemeInner { meme_id_hash: CryptoHash },
+    HiddenMemes,
+    FrozenMemes,
+    CommentsPerMeme,
+    CommentsPerMemeInner { meme_id_hash: CryptoHash },
+    RemixParents,
+    RemixesPerMeme,
+    RemixesPerMemeInner { meme_id_hash: CryptoHash },
+    Series,
+    EditionsPerSeries,
+    EditionsPerSeriesInner { series_id: u64 },
+    CreatorKeys,
+    RedeemedVouchers,
+    SoldMemes,
+    MetadataRevisions,
+    MemesPerCreator,
+    MemesPerCreatorInner { account_id_hash: CryptoHash },
+    ProvenancePerMeme,
+    StorageRecordsPerAccount,
+    OwnerFeeds,
+    OwnerFeedsInner { feed_hash: CryptoHash },
+    MemeAdditionalDataV1,
+    ListingsPerCategory,
+    ListingsPerCategoryInner { category_id_hash: CryptoHash },
 }
@@ -0,0 +1,32 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+pub const TRENDING_BUCKET_DURATION: u64 = 3_600_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone, Default, Debug)]
+pub struct TrendingScore {
+    /// Likes multiplied by score precision and decayed to `updated_at`
+    pub score: u128,
+    pub updated_at: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum TrendingWindow {
+    Hour,
+    Day,
+    Week,
+}
+
+impl TrendingWindow {
+    pub fn buckets(&self) -> u64 {
+        match self {
+            TrendingWindow::Hour => 1,
+            TrendingWindow::Day => 24,
+            TrendingWindow::Week => 168,
+        }
+    }
+}
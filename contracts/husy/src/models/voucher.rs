@@ -0,0 +1,25 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::{Base64VecU8, U128},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+use super::meme::MemeTokenId;
+
+pub const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Lazy mint permission signed off-chain by creator. Signed message is borsh serialized
+/// `(contract_id, voucher)`, so voucher cannot be redeemed on another contract
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Voucher {
+    pub creator_id: AccountId,
+    pub token_id: MemeTokenId,
+    /// sha256 of borsh serialized token metadata
+    pub metadata_hash: Base64VecU8,
+    pub price: U128,
+    /// Royalty of creator on resales, in basis points like `nft_mint` royalties
+    pub royalty: u32,
+    pub expires_at: u64,
+}
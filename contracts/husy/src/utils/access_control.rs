@@ -0,0 +1,38 @@
+use near_sdk::{env, AccountId};
+
+use crate::models::{husy::*, role::Role};
+
+impl HusyContract {
+    pub(crate) fn is_owner(&self, account_id: &AccountId) -> bool {
+        &self.owner_id == account_id
+    }
+
+    pub(crate) fn account_has_role(&self, account_id: &AccountId, role: &Role) -> bool {
+        self.roles_per_account
+            .get(account_id)
+            .map(|roles| roles.contains(role))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn assert_owner(&self) {
+        assert!(
+            self.is_owner(&env::predecessor_account_id()),
+            "Only contract owner can call this method"
+        );
+    }
+
+    /// Contract owner is allowed to do everything that any role can do
+    pub(crate) fn assert_owner_or_role(&self, role: Role) {
+        let predecessor_account_id = env::predecessor_account_id();
+        assert!(
+            self.is_owner(&predecessor_account_id)
+                || self.account_has_role(&predecessor_account_id, &role),
+            "Only contract owner or account with {:?} role can call this method",
+            role
+        );
+    }
+
+    pub(crate) fn assert_not_paused(&self) {
+        assert!(!self.paused, "Contract is paused");
+    }
+}
@@ -0,0 +1,92 @@
+use near_sdk::{env, json_types::U128, Promise};
+
+use crate::{
+    events::{AuctionCancelledLog, HusyEvent},
+    models::{
+        auction::{Auction, AuctionView, Bid},
+        husy::*,
+        meme::MemeTokenId,
+    },
+};
+
+impl Auction {
+    pub(crate) fn min_next_bid(&self) -> u128 {
+        match &self.highest_bid {
+            Some(bid) => bid.amount + self.min_increment,
+            None => self.reserve_price,
+        }
+    }
+}
+
+impl HusyContract {
+    pub(crate) fn assert_not_on_auction(&self, token_id: &MemeTokenId) {
+        assert!(self.auctions.get(token_id).is_none(), "Meme is on auction");
+    }
+
+    /// Takes highest bid out of auction and refunds storage of the bid to bidder
+    pub(crate) fn take_highest_bid(
+        &mut self,
+        token_id: &MemeTokenId,
+        auction: &mut Auction,
+    ) -> Option<Bid> {
+        let initial_storage_usage = env::storage_usage();
+        let bid = auction.highest_bid.take()?;
+        self.auctions.insert(token_id, auction);
+        let refund = self.pay_for_storage(&bid.bidder_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(bid.bidder_id.clone()).transfer(refund);
+        }
+
+        Some(bid)
+    }
+
+    /// Closes auction without selling meme. Highest bid is refunded to bidder
+    /// and released storage of auction to seller
+    pub(crate) fn close_auction(&mut self, token_id: &MemeTokenId) -> Option<Auction> {
+        let mut auction = self.auctions.get(token_id)?;
+        if let Some(bid) = self.take_highest_bid(token_id, &mut auction) {
+            Promise::new(bid.bidder_id).transfer(bid.amount);
+        }
+        let initial_storage_usage = env::storage_usage();
+        self.auctions.remove(token_id);
+        let refund = self.pay_for_storage(&auction.seller_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(auction.seller_id.clone()).transfer(refund);
+        }
+
+        HusyEvent::AuctionCancelled(vec![AuctionCancelledLog {
+            token_id: token_id.clone(),
+            seller_id: auction.seller_id.clone(),
+        }])
+        .emit();
+
+        Some(auction)
+    }
+
+    pub(crate) fn assert_auction_seller_owns(&self, token_id: &MemeTokenId, auction: &Auction) {
+        let token = self.memes_by_id.get(token_id).expect("Meme not found");
+        assert_eq!(
+            auction.seller_id, token.owner_id,
+            "Auction seller no longer owns the meme"
+        );
+    }
+
+    pub(crate) fn get_auction_view(&self, token_id: MemeTokenId) -> Option<AuctionView> {
+        let auction = self.auctions.get(&token_id)?;
+
+        Some(AuctionView {
+            token_id,
+            min_next_bid: U128(auction.min_next_bid()),
+            highest_bidder_id: auction
+                .highest_bid
+                .as_ref()
+                .map(|bid| bid.bidder_id.clone()),
+            highest_bid: auction.highest_bid.as_ref().map(|bid| U128(bid.amount)),
+            seller_id: auction.seller_id,
+            reserve_price: U128(auction.reserve_price),
+            min_increment: U128(auction.min_increment),
+            started_at: auction.started_at,
+            ends_at: auction.ends_at,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::models::{
+    category::{CategoryId, CategoryView},
+    husy::*,
+};
+
+const MAX_CATEGORY_ID_LENGTH: usize = 32;
+const MAX_CATEGORY_NAME_LENGTH: usize = 64;
+
+pub(crate) fn assert_valid_category_id(category_id: &CategoryId) {
+    assert!(
+        !category_id.is_empty() && category_id.len() <= MAX_CATEGORY_ID_LENGTH,
+        "Category id must have between 1 and {} characters",
+        MAX_CATEGORY_ID_LENGTH
+    );
+    assert!(
+        category_id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'),
+        "Category id can contain only lowercase letters, digits, '-' and '_'"
+    );
+}
+
+pub(crate) fn assert_valid_category_name(name: &str) {
+    assert!(
+        !name.trim().is_empty() && name.len() <= MAX_CATEGORY_NAME_LENGTH,
+        "Category name must have between 1 and {} characters",
+        MAX_CATEGORY_NAME_LENGTH
+    );
+}
+
+impl HusyContract {
+    pub(crate) fn get_category_view(&self, category_id: CategoryId) -> Option<CategoryView> {
+        let category = self.categories.get(&category_id)?;
+
+        Some(CategoryView {
+            id: category_id,
+            name: category.name,
+            retired: category.retired,
+            memes_count: category.memes_count,
+        })
+    }
+
+    /// Adds meme to category, category has to exist and cannot be retired
+    pub(crate) fn add_meme_to_category(&mut self, category_id: &CategoryId) {
+        let mut category = self
+            .categories
+            .get(category_id)
+            .unwrap_or_else(|| panic!("Category {} does not exist", category_id));
+        assert!(!category.retired, "Category {} is retired", category_id);
+
+        category.memes_count += 1;
+        self.categories.insert(category_id, &category);
+    }
+
+    pub(crate) fn remove_meme_from_category(&mut self, category_id: &CategoryId) {
+        if let Some(mut category) = self.categories.get(category_id) {
+            category.memes_count = category.memes_count.saturating_sub(1);
+            self.categories.insert(category_id, &category);
+        }
+    }
+}
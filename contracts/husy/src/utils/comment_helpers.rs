@@ -0,0 +1,72 @@
+use near_sdk::{env, Promise};
+
+use crate::models::{
+    comment::{Comment, CommentId, CommentView, MAX_COMMENT_LENGTH},
+    husy::*,
+    meme::MemeTokenId,
+};
+
+pub(crate) fn assert_valid_comment_text(text: &str) {
+    assert!(
+        !text.trim().is_empty() && text.len() <= MAX_COMMENT_LENGTH,
+        "Comment must have between 1 and {} characters",
+        MAX_COMMENT_LENGTH
+    );
+}
+
+impl HusyContract {
+    /// Removes comment and refunds its storage to author
+    pub(crate) fn remove_comment(
+        &mut self,
+        meme_id: &MemeTokenId,
+        comment_id: &CommentId,
+    ) -> Option<Comment> {
+        let mut comments = self.comments_per_meme.get(meme_id)?;
+        let initial_storage_usage = env::storage_usage();
+        let comment = comments.remove(comment_id)?;
+        if comments.is_empty() {
+            self.comments_per_meme.remove(meme_id);
+        } else {
+            self.comments_per_meme.insert(meme_id, &comments);
+        }
+        self.remove_storage_record(&comment.author_id);
+
+        let refund = self.pay_for_storage(&comment.author_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(comment.author_id.clone()).transfer(refund);
+        }
+
+        Some(comment)
+    }
+
+    /// Removes up to `limit` comments of meme. Returns count of comments left
+    pub(crate) fn remove_meme_comments(&mut self, meme_id: &MemeTokenId, limit: u64) -> u64 {
+        let comments = match self.comments_per_meme.get(meme_id) {
+            Some(comments) => comments,
+            None => return 0,
+        };
+        let comment_ids: Vec<CommentId> = comments.keys().take(limit as usize).collect();
+        let comments_left = comments.len() - comment_ids.len() as u64;
+        for comment_id in &comment_ids {
+            self.remove_comment(meme_id, comment_id);
+        }
+
+        comments_left
+    }
+
+    pub(crate) fn get_comment_view(
+        &self,
+        meme_id: MemeTokenId,
+        comment_id: CommentId,
+        comment: Comment,
+    ) -> CommentView {
+        CommentView {
+            id: comment_id,
+            meme_id,
+            author_id: comment.author_id,
+            text: comment.text,
+            created_at: comment.created_at,
+            parent_id: comment.parent_id,
+        }
+    }
+}
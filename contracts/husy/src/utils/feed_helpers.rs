@@ -0,0 +1,245 @@
+use near_sdk::{
+    borsh::BorshSerialize,
+    collections::{LookupMap, UnorderedSet, Vector},
+    AccountId,
+};
+
+use crate::models::{
+    category::CategoryId, husy::*, meme::MemeTokenId, meme_additional_data::MemeAdditionalData,
+    storage::StorageKey,
+};
+
+use super::hashing::{hash_category_id, hash_owner_feed};
+
+pub(crate) fn add_to_category_index(
+    index: &mut LookupMap<CategoryId, UnorderedSet<MemeTokenId>>,
+    category_id: &CategoryId,
+    meme_id: &MemeTokenId,
+    prefix: impl FnOnce() -> StorageKey,
+) {
+    let mut memes = index
+        .get(category_id)
+        .unwrap_or_else(|| UnorderedSet::new(prefix().try_to_vec().unwrap()));
+    memes.insert(meme_id);
+    index.insert(category_id, &memes);
+}
+
+pub(crate) fn remove_from_category_index(
+    index: &mut LookupMap<CategoryId, UnorderedSet<MemeTokenId>>,
+    category_id: &CategoryId,
+    meme_id: &MemeTokenId,
+) {
+    if let Some(mut memes) = index.get(category_id) {
+        memes.remove(meme_id);
+        if memes.is_empty() {
+            index.remove(category_id);
+        } else {
+            index.insert(category_id, &memes);
+        }
+    }
+}
+
+/// Category and main page variants of owner feed meme belongs to
+fn owner_feed_filters(
+    meme_additional_data: &MemeAdditionalData,
+) -> Vec<(Option<CategoryId>, bool)> {
+    let category = &meme_additional_data.category;
+    let mut filters = vec![(None, false)];
+    if category.is_some() {
+        filters.push((category.clone(), false));
+    }
+    if meme_additional_data.showed_on_main {
+        filters.push((None, true));
+        if category.is_some() {
+            filters.push((category.clone(), true));
+        }
+    }
+
+    filters
+}
+
+pub(crate) fn page(memes: &Vector<MemeTokenId>, from_index: u64, limit: u64) -> Vec<MemeTokenId> {
+    let to_index = from_index.saturating_add(limit).min(memes.len());
+    (from_index..to_index)
+        .filter_map(|index| memes.get(index))
+        .collect()
+}
+
+impl HusyContract {
+    fn add_to_owner_feed(
+        &mut self,
+        owner_id: &AccountId,
+        category: &Option<CategoryId>,
+        main_page_only: bool,
+        meme_id: &MemeTokenId,
+    ) {
+        let feed_hash = hash_owner_feed(owner_id, category, main_page_only);
+        let mut memes = self.owner_feeds.get(&feed_hash).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::OwnerFeedsInner { feed_hash }
+                    .try_to_vec()
+                    .unwrap(),
+            )
+        });
+        memes.insert(meme_id);
+        self.owner_feeds.insert(&feed_hash, &memes);
+    }
+
+    fn remove_from_owner_feed(
+        &mut self,
+        owner_id: &AccountId,
+        category: &Option<CategoryId>,
+        main_page_only: bool,
+        meme_id: &MemeTokenId,
+    ) {
+        let feed_hash = hash_owner_feed(owner_id, category, main_page_only);
+        if let Some(mut memes) = self.owner_feeds.get(&feed_hash) {
+            memes.remove(meme_id);
+            if memes.is_empty() {
+                self.owner_feeds.remove(&feed_hash);
+            } else {
+                self.owner_feeds.insert(&feed_hash, &memes);
+            }
+        }
+    }
+
+    /// Adds meme to category, main page and owner feeds according to its additional data,
+    /// hidden memes are kept out of feeds
+    pub(crate) fn add_meme_to_feeds(&mut self, meme_id: &MemeTokenId) {
+        if self.is_meme_hidden(meme_id) {
+            return;
+        }
+        let meme_additional_data = self.meme_additional_data_by_id.get(meme_id).unwrap();
+        let category = &meme_additional_data.category;
+        if let Some(category_id) = category {
+            add_to_category_index(&mut self.memes_per_category, category_id, meme_id, || {
+                StorageKey::MemesPerCategoryInner {
+                    category_id_hash: hash_category_id(category_id),
+                }
+            });
+        }
+        if let Some(token) = self.memes_by_id.get(meme_id) {
+            self.add_to_owner_feed(&token.owner_id, &None, false, meme_id);
+            if category.is_some() {
+                self.add_to_owner_feed(&token.owner_id, category, false, meme_id);
+            }
+        }
+        if meme_additional_data.showed_on_main {
+            self.add_meme_to_main_feeds(meme_id, category);
+        }
+    }
+
+    pub(crate) fn add_meme_to_main_feeds(
+        &mut self,
+        meme_id: &MemeTokenId,
+        category: &Option<CategoryId>,
+    ) {
+        self.main_page_memes.insert(meme_id);
+        if let Some(category_id) = category {
+            add_to_category_index(
+                &mut self.main_page_memes_per_category,
+                category_id,
+                meme_id,
+                || StorageKey::MainPageMemesPerCategoryInner {
+                    category_id_hash: hash_category_id(category_id),
+                },
+            );
+        }
+        if let Some(token) = self.memes_by_id.get(meme_id) {
+            self.add_to_owner_feed(&token.owner_id, &None, true, meme_id);
+            if category.is_some() {
+                self.add_to_owner_feed(&token.owner_id, category, true, meme_id);
+            }
+        }
+    }
+
+    /// Removes meme from category, main page and owner feeds, has to be called
+    /// before additional data or owner of meme is changed
+    pub(crate) fn remove_meme_from_feeds(&mut self, meme_id: &MemeTokenId) {
+        let meme_additional_data = match self.meme_additional_data_by_id.get(meme_id) {
+            Some(meme_additional_data) => meme_additional_data,
+            None => return,
+        };
+        if let Some(category_id) = &meme_additional_data.category {
+            remove_from_category_index(&mut self.memes_per_category, category_id, meme_id);
+            remove_from_category_index(
+                &mut self.main_page_memes_per_category,
+                category_id,
+                meme_id,
+            );
+        }
+        self.main_page_memes.remove(meme_id);
+        if let Some(token) = self.memes_by_id.get(meme_id) {
+            for (category, main_page_only) in owner_feed_filters(&meme_additional_data) {
+                self.remove_from_owner_feed(&token.owner_id, &category, main_page_only, meme_id);
+            }
+        }
+    }
+
+    /// Moves meme between owner feeds when it changes owner
+    pub(crate) fn move_meme_owner_feeds(
+        &mut self,
+        owner_id: &AccountId,
+        receiver_id: &AccountId,
+        meme_id: &MemeTokenId,
+    ) {
+        if self.is_meme_hidden(meme_id) {
+            return;
+        }
+        let meme_additional_data = match self.meme_additional_data_by_id.get(meme_id) {
+            Some(meme_additional_data) => meme_additional_data,
+            None => return,
+        };
+        for (category, main_page_only) in owner_feed_filters(&meme_additional_data) {
+            self.remove_from_owner_feed(owner_id, &category, main_page_only, meme_id);
+            self.add_to_owner_feed(receiver_id, &category, main_page_only, meme_id);
+        }
+    }
+
+    /// Every combination of filters is served by its own index, so page is read
+    /// directly from it. Only unfiltered feed contains hidden memes, they are left
+    /// out of the page read, which then can be shorter than `limit`.
+    pub(crate) fn get_feed_meme_ids(
+        &self,
+        from_index: u64,
+        limit: Option<u64>,
+        category: &Option<CategoryId>,
+        owner: &Option<AccountId>,
+        main_page_only: bool,
+    ) -> Vec<MemeTokenId> {
+        let feed = match (owner, category) {
+            (Some(owner_id), _) => {
+                let feed_hash = hash_owner_feed(owner_id, category, main_page_only);
+                match self.owner_feeds.get(&feed_hash) {
+                    Some(owner_memes) => owner_memes,
+                    None => return vec![],
+                }
+            }
+            (None, Some(category_id)) => {
+                let index = if main_page_only {
+                    &self.main_page_memes_per_category
+                } else {
+                    &self.memes_per_category
+                };
+                match index.get(category_id) {
+                    Some(category_memes) => category_memes,
+                    None => return vec![],
+                }
+            }
+            (None, None) if main_page_only => {
+                let memes = self.main_page_memes.as_vector();
+                return page(memes, from_index, limit.unwrap_or_else(|| memes.len()));
+            }
+            (None, None) => {
+                let memes = self.meme_additional_data_by_id.keys_as_vector();
+                return page(memes, from_index, limit.unwrap_or_else(|| memes.len()))
+                    .into_iter()
+                    .filter(|meme_id| !self.is_meme_hidden(meme_id))
+                    .collect();
+            }
+        };
+
+        let memes = feed.as_vector();
+        page(memes, from_index, limit.unwrap_or_else(|| memes.len()))
+    }
+}
@@ -1,7 +1,32 @@
-use near_sdk::{AccountId, CryptoHash, env};
+use near_sdk::{borsh::BorshSerialize, env, AccountId, CryptoHash};
+
+use crate::models::{category::CategoryId, meme::MemeTokenId};
 
 pub(crate) fn hash_account_id(account_id: &AccountId) -> CryptoHash {
     let mut hash = CryptoHash::default();
     hash.copy_from_slice(&env::sha256(account_id.as_bytes()));
     hash
 }
+
+pub(crate) fn hash_meme_id(meme_id: &MemeTokenId) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+    hash.copy_from_slice(&env::sha256(meme_id.as_bytes()));
+    hash
+}
+
+pub(crate) fn hash_category_id(category_id: &CategoryId) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+    hash.copy_from_slice(&env::sha256(category_id.as_bytes()));
+    hash
+}
+
+pub(crate) fn hash_owner_feed(
+    owner_id: &AccountId,
+    category: &Option<CategoryId>,
+    main_page_only: bool,
+) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+    let feed = (owner_id, category, main_page_only).try_to_vec().unwrap();
+    hash.copy_from_slice(&env::sha256(&feed));
+    hash
+}
@@ -2,14 +2,10 @@ use near_sdk::env;
 
 use crate::models::{
     global_likes_data::{GlobalLikesData, LikesCountingMode},
+    likes_config::LikesConfig,
     meme_additional_data::MemeAdditionalData,
 };
 
-const MAIN_AVERAGE_FACTOR: f64 = 0.8;
-const MIN_LIKES: u64 = 3;
-
-const MIN_TIME_TO_SWITCH_MODE: u64 = 604_800_000_000_000;
-
 impl GlobalLikesData {
     fn add_likes(&mut self, likes: u64, count_as_liked: bool) {
         match self.likes_counting_mode {
@@ -28,6 +24,23 @@ impl GlobalLikesData {
         }
     }
 
+    pub(crate) fn remove_likes(&mut self, likes: u64, count_as_liked: bool) {
+        match self.likes_counting_mode {
+            LikesCountingMode::FirstGroupActive => {
+                self.second_group_sum = self.second_group_sum.saturating_sub(likes as u128);
+                if count_as_liked {
+                    self.second_group_liked_memes = self.second_group_liked_memes.saturating_sub(1);
+                }
+            }
+            _ => {
+                self.first_group_sum = self.first_group_sum.saturating_sub(likes as u128);
+                if count_as_liked {
+                    self.first_group_liked_memes = self.first_group_liked_memes.saturating_sub(1);
+                }
+            }
+        }
+    }
+
     fn get_current_average(&self) -> u64 {
         let sum = match self.likes_counting_mode {
             LikesCountingMode::SecondGroupActive => self.second_group_sum,
@@ -44,17 +57,37 @@ impl GlobalLikesData {
         }
     }
 
-    pub(crate) fn try_switching_mode(&mut self) {
+    /// Once `min_time_to_switch_mode` has passed since the last swap, group which collected
+    /// likes becomes active for average and the other one collects again from zero.
+    /// Time of the swap is stored, so memes are counted anew in collecting group
+    pub(crate) fn try_switching_mode(&mut self, config: &LikesConfig) -> bool {
         let now = env::block_timestamp();
-        if now - self.last_group_swap_timestamp > MIN_TIME_TO_SWITCH_MODE {
-            self.likes_counting_mode = match self.likes_counting_mode {
-                LikesCountingMode::SecondGroupActive => LikesCountingMode::FirstGroupActive,
-                _ => LikesCountingMode::SecondGroupActive
+        if now - self.last_group_swap_timestamp <= config.min_time_to_switch_mode {
+            return false;
+        }
+
+        self.likes_counting_mode = match self.likes_counting_mode {
+            LikesCountingMode::FirstGroupActive => LikesCountingMode::SecondGroupActive,
+            _ => LikesCountingMode::FirstGroupActive,
+        };
+        // Group which starts collecting likes has to start from scratch
+        match self.likes_counting_mode {
+            LikesCountingMode::FirstGroupActive => {
+                self.second_group_sum = 0;
+                self.second_group_liked_memes = 0;
+            }
+            _ => {
+                self.first_group_sum = 0;
+                self.first_group_liked_memes = 0;
             }
         }
+        self.last_group_swap_timestamp = now;
+        true
     }
 }
 
+/// Likes of meme which is not on main page yet are added to collecting group as well.
+/// Meme is counted among liked memes of the group only by its first like since last swap
 pub(crate) fn count_new_likes_state(
     meme_likes_state: &mut MemeAdditionalData,
     global_state: &mut GlobalLikesData,
@@ -63,26 +96,40 @@ pub(crate) fn count_new_likes_state(
     meme_likes_state.likes += likes;
 
     if !meme_likes_state.showed_on_main {
-        let liked_first_time = meme_likes_state.last_counted_like_timestamp
-            == global_state.last_group_swap_timestamp;
+        let liked_first_time =
+            meme_likes_state.last_counted_like_timestamp != global_state.last_group_swap_timestamp;
         global_state.add_likes(likes, liked_first_time);
         if liked_first_time {
             meme_likes_state.last_counted_like_timestamp = global_state.last_group_swap_timestamp;
+            meme_likes_state.counted_likes = 0;
         }
+        meme_likes_state.counted_likes += likes;
+    }
+}
+
+/// Removes likes of meme from global likes data if they were counted in current group
+pub(crate) fn discount_likes_state(
+    meme_likes_state: &MemeAdditionalData,
+    global_state: &mut GlobalLikesData,
+) {
+    if meme_likes_state.last_counted_like_timestamp == global_state.last_group_swap_timestamp {
+        global_state.remove_likes(meme_likes_state.counted_likes, true);
     }
 }
 
 pub(crate) fn try_move_to_main(
     meme_likes_state: &mut MemeAdditionalData,
     global_state: &GlobalLikesData,
+    config: &LikesConfig,
 ) -> bool {
     if meme_likes_state.showed_on_main {
         return false;
     }
     let average = global_state.get_current_average();
-    let mut min_likes_amount: u64 = (MAIN_AVERAGE_FACTOR * average as f64).round() as u64;
-    if min_likes_amount < MIN_LIKES {
-        min_likes_amount = MIN_LIKES;
+    let mut min_likes_amount =
+        ((config.main_average_factor as u128 * average as u128 + 5_000) / 10_000) as u64;
+    if min_likes_amount < config.min_likes {
+        min_likes_amount = config.min_likes;
     }
 
     if meme_likes_state.likes >= min_likes_amount {
@@ -93,4 +140,163 @@ pub(crate) fn try_move_to_main(
     false
 }
 
+#[cfg(test)]
+mod test {
+    use near_sdk::MockedBlockchain;
+    use near_sdk::{test_utils::VMContextBuilder, testing_env, VMContext};
+
+    use super::*;
+
+    fn get_context(block_timestamp: u64) -> VMContext {
+        VMContextBuilder::new()
+            .block_timestamp(block_timestamp)
+            .build()
+    }
+
+    #[test]
+    fn try_switching_mode_too_early() {
+        let config = LikesConfig::new();
+        testing_env!(get_context(1));
+        let mut global_likes_data = GlobalLikesData::new();
 
+        testing_env!(get_context(1 + config.min_time_to_switch_mode));
+        assert!(!global_likes_data.try_switching_mode(&config));
+
+        assert_eq!(
+            global_likes_data.likes_counting_mode,
+            LikesCountingMode::Initial
+        );
+        assert_eq!(global_likes_data.last_group_swap_timestamp, 1);
+    }
+
+    #[test]
+    fn try_switching_mode_rotates_groups() {
+        let config = LikesConfig::new();
+        testing_env!(get_context(0));
+        let mut global_likes_data = GlobalLikesData::new();
+        global_likes_data.add_likes(10, true);
+
+        let first_swap = config.min_time_to_switch_mode + 1;
+        testing_env!(get_context(first_swap));
+        global_likes_data.try_switching_mode(&config);
+        assert_eq!(
+            global_likes_data.likes_counting_mode,
+            LikesCountingMode::FirstGroupActive
+        );
+        assert_eq!(global_likes_data.last_group_swap_timestamp, first_swap);
+        assert_eq!(global_likes_data.get_current_average(), 10);
+
+        global_likes_data.add_likes(4, true);
+        let second_swap = first_swap + config.min_time_to_switch_mode + 1;
+        testing_env!(get_context(second_swap));
+        global_likes_data.try_switching_mode(&config);
+        assert_eq!(
+            global_likes_data.likes_counting_mode,
+            LikesCountingMode::SecondGroupActive
+        );
+        assert_eq!(global_likes_data.get_current_average(), 4);
+        assert_eq!(global_likes_data.first_group_sum, 0);
+        assert_eq!(global_likes_data.first_group_liked_memes, 0);
+    }
+
+    #[test]
+    fn count_new_likes_state_counts_meme_once_per_group() {
+        testing_env!(get_context(7));
+        let mut global_likes_data = GlobalLikesData::new();
+        let mut meme_additional_data = MemeAdditionalData::default();
+
+        count_new_likes_state(&mut meme_additional_data, &mut global_likes_data, 2);
+        count_new_likes_state(&mut meme_additional_data, &mut global_likes_data, 1);
+
+        assert_eq!(meme_additional_data.likes, 3);
+        assert_eq!(meme_additional_data.counted_likes, 3);
+        assert_eq!(meme_additional_data.last_counted_like_timestamp, 7);
+        assert_eq!(global_likes_data.first_group_sum, 3);
+        assert_eq!(global_likes_data.first_group_liked_memes, 1);
+    }
+
+    #[test]
+    fn count_new_likes_state_counts_meme_again_after_swap() {
+        let config = LikesConfig::new();
+        testing_env!(get_context(0));
+        let mut global_likes_data = GlobalLikesData::new();
+        let mut meme_additional_data = MemeAdditionalData::default();
+        count_new_likes_state(&mut meme_additional_data, &mut global_likes_data, 2);
+
+        let swap = config.min_time_to_switch_mode + 1;
+        testing_env!(get_context(swap));
+        global_likes_data.try_switching_mode(&config);
+        count_new_likes_state(&mut meme_additional_data, &mut global_likes_data, 1);
+
+        assert_eq!(meme_additional_data.likes, 3);
+        assert_eq!(meme_additional_data.counted_likes, 1);
+        assert_eq!(meme_additional_data.last_counted_like_timestamp, swap);
+        assert_eq!(global_likes_data.second_group_sum, 1);
+        assert_eq!(global_likes_data.second_group_liked_memes, 1);
+    }
+
+    #[test]
+    fn try_move_to_main_uses_config() {
+        testing_env!(get_context(0));
+        let mut global_likes_data = GlobalLikesData::new();
+        global_likes_data.add_likes(20, true);
+        let config = LikesConfig {
+            main_average_factor: 5_000,
+            min_likes: 1,
+            ..LikesConfig::new()
+        };
+        let mut not_enough_likes = MemeAdditionalData {
+            likes: 9,
+            ..Default::default()
+        };
+        let mut enough_likes = MemeAdditionalData {
+            likes: 10,
+            ..Default::default()
+        };
+
+        assert!(!try_move_to_main(
+            &mut not_enough_likes,
+            &global_likes_data,
+            &config
+        ));
+        assert!(try_move_to_main(
+            &mut enough_likes,
+            &global_likes_data,
+            &config
+        ));
+        assert!(enough_likes.showed_on_main);
+    }
+
+    #[test]
+    fn discount_likes_state_of_counted_meme() {
+        testing_env!(get_context(7));
+        let mut global_likes_data = GlobalLikesData::new();
+        let mut counted_meme = MemeAdditionalData::default();
+        let mut other_meme = MemeAdditionalData::default();
+        count_new_likes_state(&mut counted_meme, &mut global_likes_data, 4);
+        count_new_likes_state(&mut other_meme, &mut global_likes_data, 2);
+
+        discount_likes_state(&counted_meme, &mut global_likes_data);
+
+        assert_eq!(global_likes_data.first_group_sum, 2);
+        assert_eq!(global_likes_data.first_group_liked_memes, 1);
+    }
+
+    #[test]
+    fn discount_likes_state_of_meme_from_previous_group() {
+        testing_env!(get_context(7));
+        let mut global_likes_data = GlobalLikesData::new();
+        global_likes_data.add_likes(5, true);
+        let meme = MemeAdditionalData {
+            likes: 3,
+            counted_likes: 3,
+            last_counted_like_timestamp: 1,
+            ..Default::default()
+        };
+
+        discount_likes_state(&meme, &mut global_likes_data);
+
+        assert_eq!(global_likes_data.first_group_sum, 5);
+        assert_eq!(global_likes_data.first_group_liked_memes, 1);
+    }
+}
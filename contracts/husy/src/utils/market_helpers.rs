@@ -0,0 +1,149 @@
+use near_sdk::{env, json_types::U128, AccountId, Promise};
+
+use crate::models::{
+    category::CategoryId,
+    husy::*,
+    listing::{Listing, ListingView},
+    meme::{MemeToken, MemeTokenId},
+    storage::StorageKey,
+};
+
+use super::{
+    feed_helpers::{add_to_category_index, page, remove_from_category_index},
+    hashing::hash_category_id,
+};
+
+impl HusyContract {
+    pub(crate) fn assert_meme_owner(&self, token_id: &MemeTokenId) -> MemeToken {
+        let token = self.memes_by_id.get(token_id).expect("Meme not found");
+        assert_eq!(
+            token.owner_id,
+            env::predecessor_account_id(),
+            "Predecessor must be meme owner"
+        );
+
+        token
+    }
+
+    /// Released storage is returned to the account which listed the meme
+    pub(crate) fn remove_listing(&mut self, token_id: &MemeTokenId) -> Option<Listing> {
+        let initial_storage_usage = env::storage_usage();
+        let listing = self.listings.remove(token_id)?;
+        self.remove_listing_from_category(token_id);
+        self.remove_storage_record(&listing.seller_id);
+        let refund = self.pay_for_storage(&listing.seller_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(listing.seller_id.clone()).transfer(refund);
+        }
+
+        Some(listing)
+    }
+
+    /// Has to be called after listing is stored or category of listed meme is changed
+    pub(crate) fn add_listing_to_category(&mut self, token_id: &MemeTokenId) {
+        let category = self
+            .meme_additional_data_by_id
+            .get(token_id)
+            .and_then(|additional_data| additional_data.category);
+        if let Some(category_id) = category {
+            add_to_category_index(
+                &mut self.listings_per_category,
+                &category_id,
+                token_id,
+                || StorageKey::ListingsPerCategoryInner {
+                    category_id_hash: hash_category_id(&category_id),
+                },
+            );
+        }
+    }
+
+    /// Has to be called before category of listed meme is changed
+    pub(crate) fn remove_listing_from_category(&mut self, token_id: &MemeTokenId) {
+        let category = self
+            .meme_additional_data_by_id
+            .get(token_id)
+            .and_then(|additional_data| additional_data.category);
+        if let Some(category_id) = category {
+            remove_from_category_index(&mut self.listings_per_category, &category_id, token_id);
+        }
+    }
+
+    /// Listing counts only while its seller still owns the meme
+    pub(crate) fn get_active_listing(
+        &self,
+        token_id: &MemeTokenId,
+    ) -> Option<(Listing, MemeToken)> {
+        let listing = self.listings.get(token_id)?;
+        let token = self.memes_by_id.get(token_id)?;
+
+        (listing.seller_id == token.owner_id).then_some((listing, token))
+    }
+
+    pub(crate) fn get_listing_view(&self, token_id: MemeTokenId) -> Option<ListingView> {
+        let (listing, token) = self.get_active_listing(&token_id)?;
+        let category = self
+            .meme_additional_data_by_id
+            .get(&token_id)
+            .and_then(|additional_data| additional_data.category);
+
+        Some(ListingView {
+            token_id,
+            owner_id: token.owner_id,
+            price: U128(listing.price),
+            listed_at: listing.listed_at,
+            category,
+        })
+    }
+
+    /// Walks owner's memes when owner is given, otherwise page is read from category
+    /// index of listings or from all listings
+    pub(crate) fn get_listing_ids(
+        &self,
+        from_index: u64,
+        limit: Option<u64>,
+        category: &Option<CategoryId>,
+        owner: &Option<AccountId>,
+    ) -> Vec<MemeTokenId> {
+        if let (None, Some(category_id)) = (owner, category) {
+            return match self.listings_per_category.get(category_id) {
+                Some(category_listings) => {
+                    let listings = category_listings.as_vector();
+                    page(
+                        listings,
+                        from_index,
+                        limit.unwrap_or_else(|| listings.len()),
+                    )
+                }
+                None => vec![],
+            };
+        }
+        let owned_memes = match owner {
+            Some(owner_id) => match self.memes_per_owner.get(owner_id) {
+                Some(owned_memes) => Some(owned_memes),
+                None => return vec![],
+            },
+            None => None,
+        };
+        let memes: Box<dyn Iterator<Item = MemeTokenId>> = match &owned_memes {
+            Some(owned_memes) => Box::new(
+                owned_memes
+                    .iter()
+                    .filter(|meme_id| self.listings.get(meme_id).is_some()),
+            ),
+            None => Box::new(self.listings.keys()),
+        };
+
+        memes
+            .filter(|meme_id| {
+                category.is_none()
+                    || &self
+                        .meme_additional_data_by_id
+                        .get(meme_id)
+                        .and_then(|additional_data| additional_data.category)
+                        == category
+            })
+            .skip(from_index as usize)
+            .take(limit.unwrap_or(u64::MAX) as usize)
+            .collect()
+    }
+}
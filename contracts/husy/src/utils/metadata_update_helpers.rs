@@ -0,0 +1,118 @@
+use near_sdk::Balance;
+
+use crate::models::{
+    husy::*,
+    meme::MemeTokenId,
+    meme_metadata::MemeTokenMetadata,
+    metadata_update::{MemeMetadataPatch, MetadataRevision, MAX_METADATA_REVISIONS},
+};
+
+pub(crate) fn changes_media(metadata: &MemeTokenMetadata, patch: &MemeMetadataPatch) -> bool {
+    (patch.media.is_some() && patch.media != metadata.media)
+        || (patch.media_hash.is_some() && patch.media_hash != metadata.media_hash)
+}
+
+pub(crate) fn apply_metadata_patch(
+    metadata: &MemeTokenMetadata,
+    patch: MemeMetadataPatch,
+    updated_at: u64,
+) -> MemeTokenMetadata {
+    MemeTokenMetadata {
+        title: patch.title.or_else(|| metadata.title.clone()),
+        description: patch.description.or_else(|| metadata.description.clone()),
+        media: patch.media.or_else(|| metadata.media.clone()),
+        media_hash: patch.media_hash.or_else(|| metadata.media_hash.clone()),
+        extra: patch.extra.or_else(|| metadata.extra.clone()),
+        updated_at: Some(updated_at),
+        ..metadata.clone()
+    }
+}
+
+impl HusyContract {
+    pub(crate) fn mark_meme_sold(&mut self, meme_id: &MemeTokenId, price: Balance) {
+        self.sold_memes.insert(meme_id);
+        self.record_sale(meme_id, price);
+    }
+
+    /// Media is what likes and buyers paid for, so it cannot be swapped afterwards
+    pub(crate) fn is_media_locked(&self, meme_id: &MemeTokenId) -> bool {
+        self.sold_memes.contains(meme_id)
+            || self
+                .meme_additional_data_by_id
+                .get(meme_id)
+                .is_some_and(|additional_data| additional_data.likes > 0)
+    }
+
+    pub(crate) fn add_metadata_revision(
+        &mut self,
+        meme_id: &MemeTokenId,
+        revision: MetadataRevision,
+    ) {
+        let mut revisions = self.metadata_revisions.get(meme_id).unwrap_or_default();
+        revisions.push(revision);
+        if revisions.len() > MAX_METADATA_REVISIONS {
+            revisions.remove(0);
+        }
+        self.metadata_revisions.insert(meme_id, &revisions);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::json_types::Base64VecU8;
+
+    use super::*;
+
+    #[test]
+    fn apply_metadata_patch_keeps_missing_fields() {
+        let metadata = MemeTokenMetadata {
+            title: Some("Cat".to_owned()),
+            description: Some("Funny cat".to_owned()),
+            media: Some("cat.png".to_owned()),
+            issued_at: Some(1),
+            ..Default::default()
+        };
+
+        let updated = apply_metadata_patch(
+            &metadata,
+            MemeMetadataPatch {
+                title: Some("Angry cat".to_owned()),
+                extra: Some("{}".to_owned()),
+                ..Default::default()
+            },
+            5,
+        );
+
+        assert_eq!(
+            updated,
+            MemeTokenMetadata {
+                title: Some("Angry cat".to_owned()),
+                description: Some("Funny cat".to_owned()),
+                media: Some("cat.png".to_owned()),
+                issued_at: Some(1),
+                updated_at: Some(5),
+                extra: Some("{}".to_owned()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn changes_media_ignores_same_values() {
+        let metadata = MemeTokenMetadata {
+            media: Some("cat.png".to_owned()),
+            media_hash: Some(Base64VecU8(vec![1; 32])),
+            ..Default::default()
+        };
+        let patch = |media: Option<&str>, media_hash: Option<u8>| MemeMetadataPatch {
+            media: media.map(|media| media.to_owned()),
+            media_hash: media_hash.map(|byte| Base64VecU8(vec![byte; 32])),
+            ..Default::default()
+        };
+
+        assert!(!changes_media(&metadata, &patch(None, None)));
+        assert!(!changes_media(&metadata, &patch(Some("cat.png"), Some(1))));
+        assert!(changes_media(&metadata, &patch(Some("dog.png"), None)));
+        assert!(changes_media(&metadata, &patch(None, Some(2))));
+    }
+}
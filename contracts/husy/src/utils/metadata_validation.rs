@@ -0,0 +1,451 @@
+use near_sdk::json_types::Base64VecU8;
+
+use crate::models::{
+    husy::*,
+    husy_metadata::HusyNFTContractMetadata,
+    meme_metadata::{
+        MemeTokenMetadata, ALLOWED_URI_SCHEMES, MAX_DESCRIPTION_LENGTH, MAX_EXTRA_LENGTH,
+        MAX_TITLE_LENGTH, METADATA_HASH_LENGTH,
+    },
+};
+
+const NFT_SPEC_PREFIX: &str = "nft-1.";
+const DATA_URL_PREFIX: &str = "data:";
+
+fn assert_max_length(value: &Option<String>, max_length: usize, field: &str) {
+    if let Some(value) = value {
+        assert!(
+            value.len() <= max_length,
+            "{} must have at most {} characters",
+            field,
+            max_length
+        );
+    }
+}
+
+fn assert_valid_hash(hash: &Option<Base64VecU8>, field: &str) {
+    if let Some(hash) = hash {
+        assert!(
+            hash.0.len() == METADATA_HASH_LENGTH,
+            "{} hash must be {}-byte sha256",
+            field,
+            METADATA_HASH_LENGTH
+        );
+    }
+}
+
+/// URI has scheme when it starts with `[a-zA-Z][a-zA-Z0-9+.-]*:`
+fn has_scheme(uri: &str) -> bool {
+    match uri.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn is_relative_uri(uri: &str) -> bool {
+    !has_scheme(uri)
+}
+
+pub(crate) fn resolve_uri(base_uri: &str, uri: &str) -> String {
+    if is_relative_uri(uri) {
+        format!(
+            "{}/{}",
+            base_uri.trim_end_matches('/'),
+            uri.trim_start_matches('/')
+        )
+    } else {
+        uri.to_owned()
+    }
+}
+
+/// URI must use one of allowed schemes and, for token metadata, be pinned by hash.
+/// Relative paths are resolved against `base_uri` of contract
+fn assert_valid_uri(
+    uri: &Option<String>,
+    hash: Option<&Option<Base64VecU8>>,
+    relative_allowed: bool,
+    field: &str,
+) {
+    if let Some(uri) = uri {
+        assert!(
+            (relative_allowed && is_relative_uri(uri) && !uri.is_empty())
+                || ALLOWED_URI_SCHEMES
+                    .iter()
+                    .any(|scheme| uri.starts_with(scheme) && uri.len() > scheme.len()),
+            "{} must use one of schemes: {}",
+            field,
+            ALLOWED_URI_SCHEMES.join(", ")
+        );
+        if let Some(hash) = hash {
+            assert!(
+                hash.is_some(),
+                "{} hash is required when {} is set",
+                field,
+                field.to_lowercase()
+            );
+        }
+    }
+}
+
+fn is_supported_spec(spec: &str) -> bool {
+    spec.strip_prefix(NFT_SPEC_PREFIX).is_some_and(|version| {
+        let parts: Vec<&str> = version.split('.').collect();
+        parts.len() == 2
+            && parts
+                .iter()
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+/// NEP-177 token metadata rules
+pub(crate) fn assert_valid_token_metadata(metadata: &MemeTokenMetadata, relative_allowed: bool) {
+    assert_max_length(&metadata.title, MAX_TITLE_LENGTH, "Title");
+    assert_max_length(&metadata.description, MAX_DESCRIPTION_LENGTH, "Description");
+    assert_max_length(&metadata.extra, MAX_EXTRA_LENGTH, "Extra");
+    assert_valid_hash(&metadata.media_hash, "Media");
+    assert_valid_hash(&metadata.reference_hash, "Reference");
+    assert_valid_uri(
+        &metadata.media,
+        Some(&metadata.media_hash),
+        relative_allowed,
+        "Media",
+    );
+    assert_valid_uri(
+        &metadata.reference,
+        Some(&metadata.reference_hash),
+        relative_allowed,
+        "Reference",
+    );
+}
+
+/// NEP-177 contract metadata rules
+pub(crate) fn assert_valid_contract_metadata(metadata: &HusyNFTContractMetadata) {
+    assert!(
+        is_supported_spec(&metadata.spec),
+        "Spec must be a recognized nft-1.x version"
+    );
+    if let Some(icon) = &metadata.icon {
+        assert!(icon.starts_with(DATA_URL_PREFIX), "Icon must be a data URL");
+    }
+    assert_valid_uri(&metadata.base_uri, None, false, "Base URI");
+    assert_valid_hash(&metadata.reference_hash, "Reference");
+    assert_valid_uri(
+        &metadata.reference,
+        Some(&metadata.reference_hash),
+        false,
+        "Reference",
+    );
+}
+
+impl HusyContract {
+    /// Relative media and reference are allowed only when contract has `base_uri`
+    pub(crate) fn assert_valid_meme_metadata(&self, metadata: &MemeTokenMetadata) {
+        let relative_allowed = self
+            .metadata
+            .get()
+            .is_some_and(|contract_metadata| contract_metadata.base_uri.is_some());
+        assert_valid_token_metadata(metadata, relative_allowed);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hash() -> Option<Base64VecU8> {
+        Some(Base64VecU8(vec![1; METADATA_HASH_LENGTH]))
+    }
+
+    fn token_metadata() -> MemeTokenMetadata {
+        MemeTokenMetadata {
+            title: Some("Cat".to_owned()),
+            description: Some("Funny cat".to_owned()),
+            media: Some("ipfs://cat".to_owned()),
+            media_hash: hash(),
+            extra: Some("{}".to_owned()),
+            reference: Some("ar://cat".to_owned()),
+            reference_hash: hash(),
+            ..Default::default()
+        }
+    }
+
+    fn contract_metadata() -> HusyNFTContractMetadata {
+        HusyNFTContractMetadata {
+            spec: "nft-1.0.0".to_owned(),
+            name: "Husy".to_owned(),
+            symbol: "HUSY".to_owned(),
+            icon: Some("data:image/svg+xml,<svg></svg>".to_owned()),
+            base_uri: Some("https://husy.io".to_owned()),
+            reference: Some("https://husy.io/reference.json".to_owned()),
+            reference_hash: hash(),
+        }
+    }
+
+    #[test]
+    fn valid_token_metadata() {
+        assert_valid_token_metadata(&token_metadata(), false);
+        assert_valid_token_metadata(&Default::default(), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Title must have at most 200 characters")]
+    fn token_metadata_long_title() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                title: Some("a".repeat(MAX_TITLE_LENGTH + 1)),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Description must have at most 2000 characters")]
+    fn token_metadata_long_description() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                description: Some("a".repeat(MAX_DESCRIPTION_LENGTH + 1)),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Extra must have at most 4096 characters")]
+    fn token_metadata_long_extra() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                extra: Some("a".repeat(MAX_EXTRA_LENGTH + 1)),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media hash must be 32-byte sha256")]
+    fn token_metadata_short_media_hash() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media_hash: Some(Base64VecU8(vec![1; 31])),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference hash must be 32-byte sha256")]
+    fn token_metadata_long_reference_hash() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                reference_hash: Some(Base64VecU8(vec![1; 33])),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media hash is required when media is set")]
+    fn token_metadata_media_without_hash() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media_hash: None,
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference hash is required when reference is set")]
+    fn token_metadata_reference_without_hash() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                reference_hash: None,
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_media_with_http_scheme() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media: Some("http://cat.png".to_owned()),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_reference_without_scheme() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                reference: Some("ipfs://".to_owned()),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    fn token_metadata_relative_uris() {
+        let metadata = MemeTokenMetadata {
+            media: Some("cat.png".to_owned()),
+            reference: Some("/cat.json".to_owned()),
+            ..token_metadata()
+        };
+
+        assert_valid_token_metadata(&metadata, true);
+        assert!(is_relative_uri("cat.png"));
+        assert!(is_relative_uri("memes/cat:1.png"));
+        assert!(!is_relative_uri("ipfs://cat.png"));
+        assert!(!is_relative_uri("javascript:alert(1)"));
+        assert!(!is_relative_uri("data:text/html,<script></script>"));
+        assert!(!is_relative_uri("http:/cat.png"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Media must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_javascript_media_with_base_uri() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media: Some("javascript:alert(1)".to_owned()),
+                ..token_metadata()
+            },
+            true,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_data_media_with_base_uri() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media: Some("data:text/html,<script>alert(1)</script>".to_owned()),
+                ..token_metadata()
+            },
+            true,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_malformed_http_reference_with_base_uri() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                reference: Some("http:/cat.json".to_owned()),
+                ..token_metadata()
+            },
+            true,
+        );
+    }
+
+    #[test]
+    fn resolve_relative_uris() {
+        assert_eq!(
+            resolve_uri("https://husy.io/", "/memes/cat.png"),
+            "https://husy.io/memes/cat.png"
+        );
+        assert_eq!(
+            resolve_uri("https://husy.io", "cat.png"),
+            "https://husy.io/cat.png"
+        );
+        assert_eq!(
+            resolve_uri("https://husy.io", "ar://cat.png"),
+            "ar://cat.png"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Media must use one of schemes: ar://, ipfs://, https://")]
+    fn token_metadata_relative_media_without_base_uri() {
+        assert_valid_token_metadata(
+            &MemeTokenMetadata {
+                media: Some("cat.png".to_owned()),
+                ..token_metadata()
+            },
+            false,
+        );
+    }
+
+    #[test]
+    fn valid_contract_metadata() {
+        assert_valid_contract_metadata(&contract_metadata());
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            spec: "nft-1.12.3".to_owned(),
+            icon: None,
+            base_uri: None,
+            reference: None,
+            reference_hash: None,
+            ..contract_metadata()
+        });
+    }
+
+    #[test]
+    fn supported_specs() {
+        assert!(is_supported_spec("nft-1.0.0"));
+        assert!(is_supported_spec("nft-1.1.0"));
+        assert!(!is_supported_spec("nft-2.0.0"));
+        assert!(!is_supported_spec("nft-1.0"));
+        assert!(!is_supported_spec("nft-1.0.x"));
+        assert!(!is_supported_spec("ft-1.0.0"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Spec must be a recognized nft-1.x version")]
+    fn contract_metadata_unknown_spec() {
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            spec: "nft-2.0.0".to_owned(),
+            ..contract_metadata()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Icon must be a data URL")]
+    fn contract_metadata_icon_link() {
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            icon: Some("https://husy.io/icon.svg".to_owned()),
+            ..contract_metadata()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Base URI must use one of schemes: ar://, ipfs://, https://")]
+    fn contract_metadata_base_uri_with_ftp_scheme() {
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            base_uri: Some("ftp://husy.io".to_owned()),
+            ..contract_metadata()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference hash is required when reference is set")]
+    fn contract_metadata_reference_without_hash() {
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            reference_hash: None,
+            ..contract_metadata()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference hash must be 32-byte sha256")]
+    fn contract_metadata_short_reference_hash() {
+        assert_valid_contract_metadata(&HusyNFTContractMetadata {
+            reference_hash: Some(Base64VecU8(vec![1; 16])),
+            ..contract_metadata()
+        });
+    }
+}
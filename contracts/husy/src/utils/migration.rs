@@ -0,0 +1,114 @@
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+};
+
+use crate::models::{
+    category::{Category, CategoryId},
+    husy::*,
+    legacy::{HusyContractV0, MemeAdditionalDataV0},
+    meme::MemeTokenId,
+    meme_additional_data::MemeAdditionalData,
+    state_version::{StateVersion, STATE_VERSION_KEY},
+};
+
+pub(crate) fn read_state_version() -> StateVersion {
+    env::storage_read(STATE_VERSION_KEY)
+        .map(|version| StateVersion::try_from_slice(&version).expect("Invalid state version"))
+        .unwrap_or(StateVersion::V0)
+}
+
+pub(crate) fn write_state_version(version: &StateVersion) {
+    env::storage_write(STATE_VERSION_KEY, &version.try_to_vec().unwrap());
+}
+
+impl HusyContract {
+    /// Builds current state from `V0` layout
+    ///
+    /// Meme additional data is migrated afterwards in batches by `migrate_memes`,
+    /// contract stays paused until all of it is migrated
+    pub(crate) fn migrate_from_v0(old: HusyContractV0) -> Self {
+        let metadata = old.metadata.get().expect("Missing contract metadata");
+        let global_likes_data = old.global_likes_data.get().unwrap();
+
+        // Legacy metadata may not pass current validation rules
+        let mut this = Self::internal_new(old.owner_id, metadata);
+        this.global_likes_data.set(&global_likes_data);
+        this.memes_per_owner = old.memes_per_owner;
+        this.memes_by_id = old.memes_by_id;
+        this.meme_metadata_by_id = old.meme_metadata_by_id;
+        this.legacy_meme_additional_data = Some(old.meme_additional_data_by_id);
+        this.paused = true;
+
+        this
+    }
+
+    /// Moves up to `limit` memes out of `V0` additional data. Returns count of memes left
+    ///
+    /// `V0` did not record meme creators, so current owner is used instead.
+    /// Categories used by memes are registered and feed indexes are rebuilt
+    pub(crate) fn migrate_legacy_memes(&mut self, limit: u64) -> u64 {
+        let mut legacy = match self.legacy_meme_additional_data.take() {
+            Some(legacy) => legacy,
+            None => return 0,
+        };
+        let global_likes_data = self.global_likes_data.get().unwrap();
+        let batch: Vec<(MemeTokenId, MemeAdditionalDataV0)> =
+            legacy.iter().take(limit as usize).collect();
+
+        for (meme_id, data) in batch {
+            legacy.remove(&meme_id);
+            let creator_id = self
+                .memes_by_id
+                .get(&meme_id)
+                .map(|meme| meme.owner_id)
+                .unwrap_or_default();
+            if let Some(category_id) = &data.category {
+                self.register_migrated_category(category_id);
+            }
+            // Legacy memes were never transferred by a marketplace, owner is the best guess
+            if !creator_id.is_empty() {
+                self.add_meme_to_creator(&creator_id, &meme_id);
+            }
+
+            // Legacy state did not track likes counted in current group,
+            // all likes of meme counted in it are the best guess
+            let counted_likes = if data.last_counted_like_timestamp
+                == global_likes_data.last_group_swap_timestamp
+            {
+                data.likes
+            } else {
+                0
+            };
+            self.meme_additional_data_by_id.insert(
+                &meme_id,
+                &MemeAdditionalData {
+                    likes: data.likes,
+                    counted_likes,
+                    showed_on_main: data.showed_on_main,
+                    last_counted_like_timestamp: data.last_counted_like_timestamp,
+                    category: data.category,
+                    creator_id,
+                    ..Default::default()
+                },
+            );
+            self.add_meme_to_feeds(&meme_id);
+        }
+
+        let memes_left = legacy.len();
+        if memes_left > 0 {
+            self.legacy_meme_additional_data = Some(legacy);
+        }
+
+        memes_left
+    }
+
+    fn register_migrated_category(&mut self, category_id: &CategoryId) {
+        let mut category = self.categories.get(category_id).unwrap_or(Category {
+            name: category_id.clone(),
+            ..Default::default()
+        });
+        category.memes_count += 1;
+        self.categories.insert(category_id, &category);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use near_sdk::{env, AccountId};
+
+use crate::{
+    events::{NftEvent, NftMintLog},
+    models::{
+        category::CategoryId,
+        husy::*,
+        meme::{MemeToken, MemeTokenId},
+        meme_additional_data::MemeAdditionalData,
+        meme_metadata::MemeTokenMetadata,
+        provenance::ProvenanceEvent,
+        series::EDITION_DELIMITER,
+    },
+};
+
+/// Ids containing edition delimiter are reserved for editions of series
+pub(crate) fn assert_valid_meme_id(token_id: &MemeTokenId) {
+    assert!(
+        !token_id.contains(EDITION_DELIMITER),
+        "Meme id cannot contain '{}'",
+        EDITION_DELIMITER
+    );
+}
+
+pub(crate) fn assert_valid_royalty(royalty: &HashMap<AccountId, u32>) {
+    assert!(
+        royalty.len() <= 5,
+        "Cannot add more than 5 royalities account"
+    );
+    let sum: u32 = royalty.values().sum();
+    assert!(
+        sum < 10_000,
+        "Sum of royalities cannot be bigger than 10 000"
+    );
+}
+
+impl HusyContract {
+    /// Stores new meme and emits mint event. Editions pass no metadata,
+    /// they share metadata stored in their series
+    pub(crate) fn internal_mint(
+        &mut self,
+        token_id: &MemeTokenId,
+        metadata: Option<&MemeTokenMetadata>,
+        owner_id: AccountId,
+        royalty: HashMap<AccountId, u32>,
+        category: Option<CategoryId>,
+        creator_id: AccountId,
+    ) {
+        assert!(
+            !self.remixes_per_meme.contains_key(token_id),
+            "Meme id is still used by remixes of burned meme"
+        );
+        assert!(
+            !self.likers_per_meme.contains_key(token_id),
+            "Likes of burned meme must be removed first"
+        );
+        assert!(
+            !self.comments_per_meme.contains_key(token_id),
+            "Comments of burned meme must be removed first"
+        );
+        let meme = MemeToken {
+            owner_id,
+            royalty,
+            ..Default::default()
+        };
+        assert!(
+            self.memes_by_id.insert(token_id, &meme).is_none(),
+            "Meme already exists"
+        );
+
+        if let Some(category) = &category {
+            self.add_meme_to_category(category);
+        }
+
+        if let Some(metadata) = metadata {
+            self.meme_metadata_by_id.insert(token_id, metadata);
+        }
+        self.add_meme_to_creator(&creator_id, token_id);
+        self.meme_additional_data_by_id.insert(
+            token_id,
+            &MemeAdditionalData {
+                creator_id,
+                category,
+                minted_at: env::block_timestamp(),
+                ..Default::default()
+            },
+        );
+        self.add_meme_to_feeds(token_id);
+
+        self.add_meme_to_owner(&meme.owner_id, token_id);
+        // Log left by burned meme with the same id starts over
+        self.provenance_per_meme.remove(token_id);
+        self.record_provenance(
+            token_id,
+            ProvenanceEvent::Mint,
+            None,
+            Some(meme.owner_id.clone()),
+        );
+
+        NftEvent::Mint(vec![NftMintLog {
+            owner_id: meme.owner_id,
+            token_ids: vec![token_id.clone()],
+            memo: None,
+        }])
+        .emit();
+    }
+}
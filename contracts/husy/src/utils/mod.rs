@@ -1,6 +1,25 @@
+pub mod access_control;
 pub mod asserts;
+pub mod auction_helpers;
 pub mod calculation;
+pub mod category_helpers;
+pub mod comment_helpers;
+pub mod feed_helpers;
 pub mod hashing;
+pub mod likes_helpers;
+pub mod market_helpers;
+pub mod metadata_update_helpers;
+pub mod metadata_validation;
+pub mod migration;
+pub mod mint_helpers;
+pub mod moderation_helpers;
+pub mod offer_helpers;
 pub mod payment;
+pub mod provenance_helpers;
+pub mod remix_helpers;
+pub mod revenue_helpers;
+pub mod series_helpers;
 pub mod state_helpers;
-pub mod likes_helpers;
\ No newline at end of file
+pub mod storage_helpers;
+pub mod trending_helpers;
+pub mod voucher_helpers;
@@ -0,0 +1,60 @@
+use near_sdk::{json_types::U128, Promise};
+
+use crate::models::{
+    husy::*,
+    meme::MemeTokenId,
+    moderation::{Report, ReportId, ReportView, MAX_REPORT_REASON_LENGTH},
+};
+
+pub(crate) fn assert_valid_report_reason(reason: &str) {
+    assert!(
+        !reason.trim().is_empty() && reason.len() <= MAX_REPORT_REASON_LENGTH,
+        "Report reason must have between 1 and {} characters",
+        MAX_REPORT_REASON_LENGTH
+    );
+}
+
+impl HusyContract {
+    pub(crate) fn is_meme_hidden(&self, meme_id: &MemeTokenId) -> bool {
+        self.hidden_memes.contains(meme_id)
+    }
+
+    pub(crate) fn assert_not_frozen(&self, meme_id: &MemeTokenId) {
+        assert!(!self.frozen_memes.contains(meme_id), "Meme is frozen");
+    }
+
+    /// Closes all reports of meme. Deposits are returned to reporters when reports
+    /// were upheld, otherwise they go to treasury. Returns number of closed reports,
+    /// which is bounded by `MAX_REPORTS_PER_MEME`
+    pub(crate) fn resolve_reports(&mut self, meme_id: &MemeTokenId, upheld: bool) -> u64 {
+        let mut meme_reports = match self.reports_per_meme.remove(meme_id) {
+            Some(meme_reports) => meme_reports,
+            None => return 0,
+        };
+        let report_ids: Vec<ReportId> = meme_reports.values().collect();
+        meme_reports.clear();
+
+        for report_id in &report_ids {
+            if let Some(report) = self.reports.remove(report_id) {
+                if upheld {
+                    Promise::new(report.reporter_id).transfer(report.deposit);
+                } else {
+                    self.treasury_balance += report.deposit;
+                }
+            }
+        }
+
+        report_ids.len() as u64
+    }
+
+    pub(crate) fn get_report_view(&self, report_id: ReportId, report: Report) -> ReportView {
+        ReportView {
+            id: report_id,
+            token_id: report.token_id,
+            reporter_id: report.reporter_id,
+            reason: report.reason,
+            deposit: U128(report.deposit),
+            reported_at: report.reported_at,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use near_sdk::{
+    borsh::BorshSerialize,
+    collections::{UnorderedMap, UnorderedSet},
+    env,
+    json_types::U128,
+    AccountId, Promise,
+};
+
+use crate::{
+    events::{HusyEvent, OfferRemovedLog},
+    models::{
+        husy::*,
+        meme::MemeTokenId,
+        offer::{Offer, OfferView},
+        storage::StorageKey,
+    },
+};
+
+use super::hashing::{hash_account_id, hash_meme_id};
+
+impl HusyContract {
+    pub(crate) fn insert_offer(
+        &mut self,
+        token_id: &MemeTokenId,
+        bidder_id: &AccountId,
+        offer: &Offer,
+    ) {
+        let mut offers = self.offers_per_meme.get(token_id).unwrap_or_else(|| {
+            UnorderedMap::new(
+                StorageKey::OffersPerMemeInner {
+                    meme_id_hash: hash_meme_id(token_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        if offers.insert(bidder_id, offer).is_none() {
+            self.add_storage_record(bidder_id);
+        }
+        self.offers_per_meme.insert(token_id, &offers);
+
+        let mut bidder_offers = self.offers_per_bidder.get(bidder_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::OffersPerBidderInner {
+                    account_id_hash: hash_account_id(bidder_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        bidder_offers.insert(token_id);
+        self.offers_per_bidder.insert(bidder_id, &bidder_offers);
+    }
+
+    /// Removes offer from both indexes, storage released by it goes back to bidder
+    pub(crate) fn remove_offer(
+        &mut self,
+        token_id: &MemeTokenId,
+        bidder_id: &AccountId,
+    ) -> Option<Offer> {
+        let mut offers = self.offers_per_meme.get(token_id)?;
+        let initial_storage_usage = env::storage_usage();
+        let offer = offers.remove(bidder_id)?;
+        if offers.is_empty() {
+            self.offers_per_meme.remove(token_id);
+        } else {
+            self.offers_per_meme.insert(token_id, &offers);
+        }
+
+        if let Some(mut bidder_offers) = self.offers_per_bidder.get(bidder_id) {
+            bidder_offers.remove(token_id);
+            if bidder_offers.is_empty() {
+                self.offers_per_bidder.remove(bidder_id);
+            } else {
+                self.offers_per_bidder.insert(bidder_id, &bidder_offers);
+            }
+        }
+        self.remove_storage_record(bidder_id);
+
+        let refund = self.pay_for_storage(bidder_id, initial_storage_usage, 0);
+        if refund > 0 {
+            Promise::new(bidder_id.clone()).transfer(refund);
+        }
+
+        Some(offer)
+    }
+
+    /// Removes offer and returns escrowed amount to bidder
+    pub(crate) fn refund_offer(
+        &mut self,
+        token_id: &MemeTokenId,
+        bidder_id: &AccountId,
+    ) -> Option<Offer> {
+        let offer = self.remove_offer(token_id, bidder_id)?;
+        Promise::new(bidder_id.clone()).transfer(offer.amount);
+
+        Some(offer)
+    }
+
+    /// Refunds offer of meme that expired first, escrowed amount goes back to its bidder
+    pub(crate) fn evict_expired_offer(
+        &mut self,
+        token_id: &MemeTokenId,
+    ) -> Option<OfferRemovedLog> {
+        let now = env::block_timestamp();
+        let bidder_id = self
+            .offers_per_meme
+            .get(token_id)?
+            .iter()
+            .filter(|(_, offer)| offer.is_expired(now))
+            .min_by_key(|(_, offer)| offer.expires_at)
+            .map(|(bidder_id, _)| bidder_id)?;
+        let offer = self.refund_offer(token_id, &bidder_id)?;
+
+        Some(OfferRemovedLog {
+            token_id: token_id.clone(),
+            bidder_id,
+            amount: U128(offer.amount),
+        })
+    }
+
+    /// Refunds all offers for meme, their count is bounded by `MAX_OFFERS_PER_MEME`
+    pub(crate) fn refund_offers(&mut self, token_id: &MemeTokenId) {
+        let bidder_ids: Vec<AccountId> = match self.offers_per_meme.get(token_id) {
+            Some(offers) => offers.keys().collect(),
+            None => return,
+        };
+
+        let removed: Vec<OfferRemovedLog> = bidder_ids
+            .into_iter()
+            .filter_map(|bidder_id| {
+                let offer = self.refund_offer(token_id, &bidder_id)?;
+                Some(OfferRemovedLog {
+                    token_id: token_id.clone(),
+                    bidder_id,
+                    amount: U128(offer.amount),
+                })
+            })
+            .collect();
+        HusyEvent::OfferCancelled(removed).emit();
+    }
+
+    pub(crate) fn get_offer_view(
+        &self,
+        token_id: MemeTokenId,
+        bidder_id: AccountId,
+        offer: Offer,
+    ) -> OfferView {
+        OfferView {
+            token_id,
+            bidder_id,
+            amount: U128(offer.amount),
+            created_at: offer.created_at,
+            expires_at: offer.expires_at,
+        }
+    }
+}
@@ -2,11 +2,13 @@ use std::{collections::HashMap, mem::size_of};
 
 use near_sdk::{env, AccountId, Balance, Promise};
 
+use crate::models::payout::Payout;
+
 /// Takes function F as param
-/// Function F can touple 2 of values 
-/// 
+/// Function F can touple 2 of values
+///
 /// First will be returned from with_refund function
-/// 
+///
 /// Second is the account which will get refund
 pub(crate) fn with_refund<F, R>(fun: F) -> R
 where
@@ -49,3 +51,11 @@ pub(crate) fn refund_approved_account_ids(
     let storage_released: u64 = approved_account_ids.keys().map(bytes_for_account_id).sum();
     Promise::new(account_id).transfer(Balance::from(storage_released) * env::storage_byte_cost())
 }
+
+pub(crate) fn pay_out(payout: &Payout) {
+    for (account_id, amount) in payout.payout.iter() {
+        if amount.0 > 0 {
+            Promise::new(account_id.to_owned()).transfer(amount.0);
+        }
+    }
+}
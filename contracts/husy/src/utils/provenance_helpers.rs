@@ -0,0 +1,43 @@
+use near_sdk::{env, json_types::U128, AccountId, Balance};
+
+use crate::models::{
+    husy::*,
+    meme::MemeTokenId,
+    provenance::{ProvenanceEntry, ProvenanceEvent, MAX_PROVENANCE_ENTRIES},
+};
+
+impl HusyContract {
+    pub(crate) fn record_provenance(
+        &mut self,
+        meme_id: &MemeTokenId,
+        event: ProvenanceEvent,
+        from_id: Option<AccountId>,
+        to_id: Option<AccountId>,
+    ) {
+        let mut entries = self.provenance_per_meme.get(meme_id).unwrap_or_default();
+        entries.push(ProvenanceEntry {
+            event,
+            from_id,
+            to_id,
+            price: None,
+            timestamp: env::block_timestamp(),
+        });
+        if entries.len() > MAX_PROVENANCE_ENTRIES {
+            entries.remove(1);
+        }
+        self.provenance_per_meme.insert(meme_id, &entries);
+    }
+
+    /// Sale happens in the same call as transfer or mint of meme,
+    /// so its price completes entry written by that transfer or mint
+    pub(crate) fn record_sale(&mut self, meme_id: &MemeTokenId, price: Balance) {
+        let mut entries = self.provenance_per_meme.get(meme_id).unwrap_or_default();
+        if let Some(entry) = entries.last_mut() {
+            if entry.event == ProvenanceEvent::Transfer {
+                entry.event = ProvenanceEvent::Sale;
+            }
+            entry.price = Some(U128(price));
+            self.provenance_per_meme.insert(meme_id, &entries);
+        }
+    }
+}
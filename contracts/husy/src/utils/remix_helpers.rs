@@ -0,0 +1,58 @@
+use near_sdk::{borsh::BorshSerialize, collections::UnorderedSet};
+
+use crate::models::{husy::*, meme::MemeTokenId, storage::StorageKey};
+
+use super::hashing::hash_meme_id;
+
+impl HusyContract {
+    pub(crate) fn add_remix(&mut self, parent_id: &MemeTokenId, meme_id: &MemeTokenId) {
+        self.remix_parents.insert(meme_id, parent_id);
+
+        let mut remixes = self.remixes_per_meme.get(parent_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::RemixesPerMemeInner {
+                    meme_id_hash: hash_meme_id(parent_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        remixes.insert(meme_id);
+        self.remixes_per_meme.insert(parent_id, &remixes);
+    }
+
+    /// Detaches burned meme from its parent. Remixes of burned meme keep
+    /// pointing to it, so its id cannot be minted again until they are burned
+    pub(crate) fn remove_remix_links(&mut self, meme_id: &MemeTokenId) {
+        if let Some(parent_id) = self.remix_parents.remove(meme_id) {
+            if let Some(mut remixes) = self.remixes_per_meme.get(&parent_id) {
+                remixes.remove(meme_id);
+                if remixes.is_empty() {
+                    self.remixes_per_meme.remove(&parent_id);
+                } else {
+                    self.remixes_per_meme.insert(&parent_id, &remixes);
+                }
+            }
+        }
+    }
+
+    /// Up to `limit` ancestors of meme starting from the one it remixes, lineage ends
+    /// at first burned ancestor
+    pub(crate) fn get_lineage_ids(&self, meme_id: &MemeTokenId, limit: u64) -> Vec<MemeTokenId> {
+        let mut lineage = vec![];
+        let mut current = meme_id.clone();
+        while (lineage.len() as u64) < limit {
+            let parent_id = match self.remix_parents.get(&current) {
+                Some(parent_id) => parent_id,
+                None => break,
+            };
+            if !self.memes_by_id.contains_key(&parent_id) {
+                break;
+            }
+            lineage.push(parent_id.clone());
+            current = parent_id;
+        }
+
+        lineage
+    }
+}
@@ -0,0 +1,44 @@
+use near_sdk::{json_types::U128, Balance};
+
+use crate::{
+    models::{husy::*, like_revenue_split::LikeRevenueSplit, meme::MemeTokenId},
+    utils::{calculation::calculate_procentage, payment::pay_out},
+};
+
+impl LikeRevenueSplit {
+    pub(crate) fn assert_valid(&self) {
+        let sum =
+            self.creator_share as u64 + self.treasury_share as u64 + self.reward_pool_share as u64;
+        assert_eq!(sum, 10_000, "Sum of revenue shares must be equal to 10 000");
+    }
+}
+
+impl HusyContract {
+    /// Pays creator share to meme owner and royalty holders,
+    /// rest of the payment is kept in treasury and reward pool
+    pub(crate) fn distribute_like_payment(&mut self, meme_id: &MemeTokenId, amount: Balance) {
+        let split = self.like_revenue_split.get().unwrap();
+        let creator_amount = calculate_procentage(split.creator_share, amount);
+        let reward_pool_amount = calculate_procentage(split.reward_pool_share, amount);
+        // Treasury takes rounding leftovers
+        let treasury_amount = amount - creator_amount - reward_pool_amount;
+
+        self.reward_pool_balance += reward_pool_amount;
+        self.treasury_balance += treasury_amount;
+
+        if creator_amount > 0 {
+            let payout = self.get_meme_payout(meme_id.to_owned(), U128(creator_amount), u32::MAX);
+            pay_out(&payout);
+        }
+    }
+}
+
+pub(crate) fn withdraw_from(balance: &mut Balance, amount: Balance) {
+    assert!(amount > 0, "Amount must be greater than 0");
+    assert!(
+        amount <= *balance,
+        "Not enough funds. Available: {} yoctoNEAR",
+        balance
+    );
+    *balance -= amount;
+}
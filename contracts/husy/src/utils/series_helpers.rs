@@ -0,0 +1,123 @@
+use near_sdk::{borsh::BorshSerialize, collections::UnorderedSet, json_types::U128, AccountId};
+
+use crate::models::{
+    husy::*,
+    meme::MemeTokenId,
+    meme_metadata::MemeTokenMetadata,
+    series::{Series, SeriesId, SeriesView, EDITION_DELIMITER},
+    storage::StorageKey,
+};
+
+pub(crate) fn parse_edition_id(token_id: &MemeTokenId) -> Option<(SeriesId, u64)> {
+    let (series_id, edition_no) = token_id.split_once(EDITION_DELIMITER)?;
+
+    Some((series_id.parse().ok()?, edition_no.parse().ok()?))
+}
+
+/// Metadata of edition is derived from series template, so it is not stored per token
+pub(crate) fn edition_metadata(series: &Series, edition_no: u64) -> MemeTokenMetadata {
+    MemeTokenMetadata {
+        title: series
+            .metadata
+            .title
+            .as_ref()
+            .map(|title| format!("{} #{}", title, edition_no)),
+        copies: Some(series.max_copies),
+        ..series.metadata.clone()
+    }
+}
+
+impl HusyContract {
+    pub(crate) fn get_meme_metadata(&self, meme_id: &MemeTokenId) -> Option<MemeTokenMetadata> {
+        self.meme_metadata_by_id.get(meme_id).or_else(|| {
+            let (series_id, edition_no) = parse_edition_id(meme_id)?;
+            let series = self.series.get(&series_id)?;
+
+            Some(edition_metadata(&series, edition_no))
+        })
+    }
+
+    pub(crate) fn mint_next_edition(
+        &mut self,
+        series_id: SeriesId,
+        receiver_id: AccountId,
+    ) -> MemeTokenId {
+        let mut series = self.series.get(&series_id).expect("Series not found");
+        assert!(
+            series.minted < series.max_copies,
+            "All editions of series are minted"
+        );
+        series.minted += 1;
+        self.series.insert(&series_id, &series);
+
+        let token_id = format!("{}{}{}", series_id, EDITION_DELIMITER, series.minted);
+        self.internal_mint(
+            &token_id,
+            None,
+            receiver_id,
+            series.royalty,
+            series.category,
+            series.creator_id,
+        );
+
+        let mut editions = self.editions_per_series.get(&series_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::EditionsPerSeriesInner { series_id }
+                    .try_to_vec()
+                    .unwrap(),
+            )
+        });
+        editions.insert(&token_id);
+        self.editions_per_series.insert(&series_id, &editions);
+
+        token_id
+    }
+
+    pub(crate) fn remove_edition(&mut self, token_id: &MemeTokenId) {
+        let (series_id, _) = match parse_edition_id(token_id) {
+            Some(edition) => edition,
+            None => return,
+        };
+        if let Some(mut editions) = self.editions_per_series.get(&series_id) {
+            editions.remove(token_id);
+            if editions.is_empty() {
+                self.editions_per_series.remove(&series_id);
+            } else {
+                self.editions_per_series.insert(&series_id, &editions);
+            }
+        }
+    }
+
+    pub(crate) fn get_series_view(&self, series_id: SeriesId) -> Option<SeriesView> {
+        let series = self.series.get(&series_id)?;
+
+        Some(SeriesView {
+            series_id,
+            creator_id: series.creator_id,
+            metadata: series.metadata,
+            royalty: series.royalty,
+            category: series.category,
+            max_copies: series.max_copies,
+            minted: series.minted,
+            supply: self
+                .editions_per_series
+                .get(&series_id)
+                .map(|editions| editions.len())
+                .unwrap_or(0),
+            price: series.price.map(U128),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_edition_id_test() {
+        assert_eq!(parse_edition_id(&"3:14".to_owned()), Some((3, 14)));
+        assert_eq!(parse_edition_id(&"meme".to_owned()), None);
+        assert_eq!(parse_edition_id(&"cat:1".to_owned()), None);
+        assert_eq!(parse_edition_id(&"3:".to_owned()), None);
+    }
+}
@@ -1,22 +1,29 @@
 use std::collections::HashMap;
 
 use near_sdk::{
-    assert_one_yocto, borsh::BorshSerialize, collections::UnorderedSet, env, json_types::U128,
-    AccountId,
+    assert_one_yocto,
+    borsh::BorshSerialize,
+    collections::{UnorderedMap, UnorderedSet},
+    env,
+    json_types::U128,
+    AccountId, Promise,
 };
 
 use crate::{
+    events::{NftEvent, NftTransferLog},
     models::{
         husy::*,
         meme::{MemeToken, MemeTokenId, MemeTokenView},
+        meme_like::{MemeLike, MemeLikeView},
         meme_metadata::MemeTokenMetadata,
         payout::Payout,
+        provenance::ProvenanceEvent,
         storage::StorageKey,
     },
-    utils::calculation::calculate_procentage,
+    utils::{calculation::calculate_procentage, metadata_validation::resolve_uri},
 };
 
-use super::hashing::hash_account_id;
+use super::hashing::{hash_account_id, hash_meme_id};
 
 impl HusyContract {
     pub(crate) fn add_meme_to_owner(&mut self, owner_id: &AccountId, meme_id: &MemeTokenId) {
@@ -54,6 +61,36 @@ impl HusyContract {
         }
     }
 
+    /// Memes stay listed under their creator after transfer, until burned
+    pub(crate) fn add_meme_to_creator(&mut self, creator_id: &AccountId, meme_id: &MemeTokenId) {
+        let mut created_memes = self.memes_per_creator.get(creator_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::MemesPerCreatorInner {
+                    account_id_hash: hash_account_id(creator_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        created_memes.insert(meme_id);
+        self.memes_per_creator.insert(creator_id, &created_memes);
+    }
+
+    pub(crate) fn remove_meme_from_creator(
+        &mut self,
+        creator_id: &AccountId,
+        meme_id: &MemeTokenId,
+    ) {
+        if let Some(mut created_memes) = self.memes_per_creator.get(creator_id) {
+            created_memes.remove(meme_id);
+            if created_memes.is_empty() {
+                self.memes_per_creator.remove(creator_id);
+            } else {
+                self.memes_per_creator.insert(creator_id, &created_memes);
+            }
+        }
+    }
+
     pub(crate) fn swap_meme_owner(
         &mut self,
         owner_id: &AccountId,
@@ -61,7 +98,98 @@ impl HusyContract {
         meme_id: &MemeTokenId,
     ) {
         self.remove_meme_from_owner(owner_id, meme_id);
-        self.add_meme_to_owner(receiver_id, meme_id)
+        self.add_meme_to_owner(receiver_id, meme_id);
+        self.move_meme_owner_feeds(owner_id, receiver_id, meme_id);
+    }
+
+    pub(crate) fn record_like(
+        &mut self,
+        account_id: &AccountId,
+        meme_id: &MemeTokenId,
+        likes: u64,
+    ) {
+        let now = env::block_timestamp();
+        let mut account_likes = self.likes_per_account.get(account_id).unwrap_or_else(|| {
+            UnorderedMap::new(
+                StorageKey::LikesPerAccountInner {
+                    account_id_hash: hash_account_id(account_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        let mut like = account_likes.get(meme_id).unwrap_or(MemeLike {
+            first_liked_at: now,
+            ..Default::default()
+        });
+        like.likes += likes;
+        like.last_liked_at = now;
+        account_likes.insert(meme_id, &like);
+        self.likes_per_account.insert(account_id, &account_likes);
+
+        let mut likers = self.likers_per_meme.get(meme_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::LikersPerMemeInner {
+                    meme_id_hash: hash_meme_id(meme_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        if likers.insert(account_id) {
+            self.likers_per_meme.insert(meme_id, &likers);
+        }
+    }
+
+    /// Removes ledger entries of up to `limit` likers of meme, storage released
+    /// by each entry goes back to its liker. Returns count of likers left
+    pub(crate) fn remove_meme_likes(&mut self, meme_id: &MemeTokenId, limit: u64) -> u64 {
+        let mut likers = match self.likers_per_meme.get(meme_id) {
+            Some(likers) => likers,
+            None => return 0,
+        };
+        let account_ids: Vec<AccountId> = likers.iter().take(limit as usize).collect();
+
+        for account_id in account_ids {
+            let initial_storage_usage = env::storage_usage();
+            likers.remove(&account_id);
+            if likers.is_empty() {
+                self.likers_per_meme.remove(meme_id);
+            } else {
+                self.likers_per_meme.insert(meme_id, &likers);
+            }
+            if let Some(mut account_likes) = self.likes_per_account.get(&account_id) {
+                account_likes.remove(meme_id);
+                if account_likes.is_empty() {
+                    self.likes_per_account.remove(&account_id);
+                } else {
+                    self.likes_per_account.insert(&account_id, &account_likes);
+                }
+            }
+
+            let refund = self.pay_for_storage(&account_id, initial_storage_usage, 0);
+            if refund > 0 {
+                Promise::new(account_id).transfer(refund);
+            }
+        }
+
+        likers.len()
+    }
+
+    pub(crate) fn get_like_view(
+        &self,
+        account_id: AccountId,
+        meme_id: MemeTokenId,
+    ) -> Option<MemeLikeView> {
+        let like = self.likes_per_account.get(&account_id)?.get(&meme_id)?;
+
+        Some(MemeLikeView {
+            meme_id,
+            account_id,
+            likes: like.likes,
+            first_liked_at: like.first_liked_at,
+            last_liked_at: like.last_liked_at,
+        })
     }
 
     pub(crate) fn get_meme_view(
@@ -70,11 +198,24 @@ impl HusyContract {
         metadata: Option<MemeTokenMetadata>,
     ) -> Option<MemeTokenView> {
         let token = self.memes_by_id.get(&id)?;
-        let metadata = match metadata {
+        let mut metadata = match metadata {
             Some(metadata) => metadata,
-            None => self.meme_metadata_by_id.get(&id)?,
+            None => self.get_meme_metadata(&id)?,
         };
+        if self.resolve_relative_uris {
+            if let Some(base_uri) = self.metadata.get().and_then(|metadata| metadata.base_uri) {
+                metadata.media = metadata.media.map(|media| resolve_uri(&base_uri, &media));
+                metadata.reference = metadata
+                    .reference
+                    .map(|reference| resolve_uri(&base_uri, &reference));
+            }
+        }
         let additional_data = self.meme_additional_data_by_id.get(&id)?;
+        let comments = self
+            .comments_per_meme
+            .get(&id)
+            .map(|comments| comments.len())
+            .unwrap_or(0);
 
         Some(MemeTokenView {
             metadata,
@@ -85,6 +226,9 @@ impl HusyContract {
             likes: additional_data.likes,
             category: additional_data.category,
             showed_on_main: additional_data.showed_on_main,
+            comments,
+            creator_id: additional_data.creator_id,
+            minted_at: additional_data.minted_at,
         })
     }
 
@@ -97,12 +241,20 @@ impl HusyContract {
         memo: Option<String>,
     ) -> MemeToken {
         assert_one_yocto();
+        self.assert_not_paused();
+        self.assert_not_on_auction(&token_id);
 
         let token = self
             .memes_by_id
             .get(&token_id)
             .expect("Token id is invalid");
 
+        let authorized_id = if token.owner_id != sender_id {
+            Some(sender_id.clone())
+        } else {
+            None
+        };
+
         if token.owner_id != sender_id {
             if !token.approved_account_ids.contains_key(&sender_id) {
                 panic!("Unauthorized")
@@ -117,30 +269,59 @@ impl HusyContract {
             }
         }
 
-        assert_eq!(token.owner_id, sender_id, "Unauthorized");
+        self.internal_meme_transfer(token_id, token, receiver_id, authorized_id, memo)
+    }
+
+    /// Moves meme to receiver without checking who requested the transfer
+    pub(crate) fn internal_meme_transfer(
+        &mut self,
+        token_id: MemeTokenId,
+        token: MemeToken,
+        receiver_id: AccountId,
+        authorized_id: Option<AccountId>,
+        memo: Option<String>,
+    ) -> MemeToken {
         assert_ne!(
-            receiver_id, sender_id,
+            receiver_id, token.owner_id,
             "Owner and recievers should be different",
         );
+        self.assert_not_frozen(&token_id);
 
-        self.swap_meme_owner(&sender_id, &receiver_id, &token_id);
+        self.swap_meme_owner(&token.owner_id, &receiver_id, &token_id);
+        self.remove_listing(&token_id);
+        self.record_provenance(
+            &token_id,
+            ProvenanceEvent::Transfer,
+            Some(token.owner_id.clone()),
+            Some(receiver_id.clone()),
+        );
 
         self.memes_by_id.insert(
             &token_id,
             &MemeToken {
-                owner_id: receiver_id,
+                owner_id: receiver_id.clone(),
                 next_approval_id: token.next_approval_id,
+                royalty: token.royalty.clone(),
                 ..Default::default()
             },
         );
 
-        if let Some(memo) = memo {
-            env::log(format!("Memo: {}", memo).as_bytes());
-        }
+        NftEvent::Transfer(vec![NftTransferLog {
+            authorized_id,
+            old_owner_id: token.owner_id.clone(),
+            new_owner_id: receiver_id,
+            token_ids: vec![token_id],
+            memo,
+        }])
+        .emit();
 
         token // Token before transfer
     }
 
+    /// Splits balance between royalty receivers and owner of meme
+    ///
+    /// Owner is always part of payout and receives rounding dust
+    /// together with own royalty share
     pub(crate) fn get_meme_payout(
         &self,
         token_id: MemeTokenId,
@@ -148,8 +329,14 @@ impl HusyContract {
         max_len_payout: u32,
     ) -> Payout {
         let token = self.memes_by_id.get(&token_id).expect("Invalid token id");
+        let payout_len = token
+            .royalty
+            .keys()
+            .filter(|key| *key != &token.owner_id)
+            .count() as u32
+            + 1;
         assert!(
-            (token.royalty.len() as u32) < max_len_payout,
+            payout_len <= max_len_payout,
             "Market cannot payout to that many receivers"
         );
 
@@ -183,7 +370,7 @@ mod test {
     use crate::models::meme_metadata::MemeTokenMetadata;
 
     use super::*;
-    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::test_utils::{get_logs, VMContextBuilder};
     use near_sdk::MockedBlockchain;
     use near_sdk::{testing_env, VMContext};
 
@@ -209,7 +396,11 @@ mod test {
             likes: 1,
             showed_on_main: true,
             last_counted_like_timestamp: 0,
+            counted_likes: 0,
             category: Some("category".to_owned()),
+            category_changed: false,
+            creator_id: "creator.testnet".to_owned(),
+            minted_at: 5,
         };
         let meme_token_metadata = MemeTokenMetadata {
             title: Some("title".to_owned()),
@@ -240,6 +431,9 @@ mod test {
                 likes: 1,
                 showed_on_main: true,
                 category: Some("category".to_owned()),
+                comments: 0,
+                creator_id: "creator.testnet".to_owned(),
+                minted_at: 5,
             })
         )
     }
@@ -369,4 +563,48 @@ mod test {
 
         contract.remove_meme_from_owner(&owner_id, &test_meme_id);
     }
+
+    #[test]
+    fn nft_meme_transfer_success() {
+        let owner_id = "owner.testnet".to_owned();
+        let receiver_id = "receiver.testnet".to_owned();
+        let meme_id = "meme.testnet".to_owned();
+        let mut context = get_context(owner_id.clone());
+        context.attached_deposit = 1;
+        testing_env!(context);
+        let mut contract = HusyContract::new_default("contract_owner.testnet".to_owned());
+        contract.memes_by_id.insert(
+            &meme_id,
+            &MemeToken {
+                owner_id: owner_id.clone(),
+                next_approval_id: 3,
+                ..Default::default()
+            },
+        );
+        contract.add_meme_to_owner(&owner_id, &meme_id);
+
+        contract.nft_meme_transfer(
+            owner_id.clone(),
+            receiver_id.clone(),
+            meme_id.clone(),
+            None,
+            Some("gift".to_owned()),
+        );
+
+        let token = contract.memes_by_id.get(&meme_id).unwrap();
+        assert_eq!(token.owner_id, receiver_id);
+        assert_eq!(token.next_approval_id, 3);
+        assert!(contract.memes_per_owner.get(&owner_id).is_none());
+        assert!(contract
+            .memes_per_owner
+            .get(&receiver_id)
+            .unwrap()
+            .contains(&meme_id));
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"old_owner_id":"owner.testnet","new_owner_id":"receiver.testnet","token_ids":["meme.testnet"],"memo":"gift"}]}"#
+            ]
+        );
+    }
 }
@@ -0,0 +1,116 @@
+use near_sdk::{env, json_types::U128, AccountId, Balance, Promise, StorageUsage};
+
+use crate::models::{husy::*, storage_balance::StorageBalance};
+
+const MAX_ACCOUNT_ID_LENGTH: usize = 64;
+
+impl HusyContract {
+    /// Measures how much storage registration of the longest possible account takes
+    pub(crate) fn measure_account_storage_usage(&mut self) {
+        let initial_storage_usage = env::storage_usage();
+        let tmp_account_id = "a".repeat(MAX_ACCOUNT_ID_LENGTH);
+        self.storage_balances.insert(&tmp_account_id, &0);
+        self.account_storage_usage = env::storage_usage() - initial_storage_usage;
+        self.storage_balances.remove(&tmp_account_id);
+    }
+
+    pub(crate) fn storage_balance_min(&self) -> Balance {
+        Balance::from(self.account_storage_usage) * env::storage_byte_cost()
+    }
+
+    pub(crate) fn get_storage_balance(&self, account_id: &AccountId) -> Option<StorageBalance> {
+        self.storage_balances
+            .get(account_id)
+            .map(|total| StorageBalance {
+                total: U128(total),
+                available: U128(total - self.storage_balance_min()),
+            })
+    }
+
+    /// Offers, listings and comments of account were paid from its storage balance
+    pub(crate) fn has_storage_records(&self, account_id: &AccountId) -> bool {
+        self.storage_records_per_account.contains_key(account_id)
+    }
+
+    /// Counts offer, listing or comment whose storage is paid by account
+    pub(crate) fn add_storage_record(&mut self, account_id: &AccountId) {
+        let records = self
+            .storage_records_per_account
+            .get(account_id)
+            .unwrap_or(0);
+        self.storage_records_per_account
+            .insert(account_id, &(records + 1));
+    }
+
+    pub(crate) fn remove_storage_record(&mut self, account_id: &AccountId) {
+        match self.storage_records_per_account.get(account_id) {
+            Some(records) if records > 1 => {
+                self.storage_records_per_account
+                    .insert(account_id, &(records - 1));
+            }
+            _ => {
+                self.storage_records_per_account.remove(account_id);
+            }
+        }
+    }
+
+    /// Pays for storage used since `initial_storage_usage`
+    ///
+    /// Cost is taken from prepaid storage balance of account when it is big enough,
+    /// otherwise from `deposit`. Released storage goes back to storage balance of
+    /// registered account. Returns part of `deposit` which was not used
+    pub(crate) fn pay_for_storage(
+        &mut self,
+        account_id: &AccountId,
+        initial_storage_usage: StorageUsage,
+        deposit: Balance,
+    ) -> Balance {
+        let final_storage_usage = env::storage_usage();
+        let storage_balance = self.storage_balances.get(account_id);
+
+        if initial_storage_usage > final_storage_usage {
+            let released_storage = initial_storage_usage - final_storage_usage;
+            let released_cost = env::storage_byte_cost() * Balance::from(released_storage);
+            return match storage_balance {
+                Some(total) => {
+                    self.storage_balances
+                        .insert(account_id, &(total + released_cost));
+                    deposit
+                }
+                None => deposit + released_cost,
+            };
+        }
+
+        let required_additional_storage = final_storage_usage - initial_storage_usage;
+        let required_cost = env::storage_byte_cost() * Balance::from(required_additional_storage);
+        if required_cost == 0 {
+            return deposit;
+        }
+        if let Some(total) = storage_balance {
+            if total - self.storage_balance_min() >= required_cost {
+                self.storage_balances
+                    .insert(account_id, &(total - required_cost));
+                return deposit;
+            }
+        }
+
+        assert!(
+            required_cost <= deposit,
+            "Must attach {} yoctoNEAR or deposit it with storage_deposit to cover storage",
+            required_cost,
+        );
+        deposit - required_cost
+    }
+
+    /// Pays for storage used since `initial_storage_usage` by predecessor
+    /// and refunds unused attached deposit
+    pub(crate) fn pay_for_storage_and_refund(&mut self, initial_storage_usage: StorageUsage) {
+        let account_id = env::predecessor_account_id();
+        let refund =
+            self.pay_for_storage(&account_id, initial_storage_usage, env::attached_deposit());
+
+        if refund > 0 {
+            Promise::new(account_id).transfer(refund);
+        }
+    }
+}
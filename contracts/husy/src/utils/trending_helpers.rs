@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use near_sdk::{borsh::BorshSerialize, collections::UnorderedSet, env};
+
+use crate::models::{
+    husy::*,
+    meme::MemeTokenId,
+    storage::StorageKey,
+    trending::{TrendingScore, TrendingWindow, TRENDING_BUCKET_DURATION},
+};
+
+const SCORE_PRECISION: u128 = 1_000_000;
+/// Bucket entries removed by one prune, missing bucket counts as one entry
+const MAX_PRUNED_ENTRIES: u64 = 50;
+/// Bucket entries ranked by trending view, newest buckets are read first
+const MAX_TRENDING_SCANNED_ENTRIES: usize = 200;
+
+/// Halves score for every full half-life, the rest of the time is
+/// approximated linearly between two consecutive halvings
+pub(crate) fn decay_score(score: u128, elapsed: u64, half_life: u64) -> u128 {
+    let halvings = elapsed / half_life;
+    if halvings >= 128 {
+        return 0;
+    }
+    let halved = score >> halvings;
+    let remainder = (elapsed % half_life) as u128;
+
+    halved - halved * remainder / (2 * half_life as u128)
+}
+
+impl HusyContract {
+    pub(crate) fn get_trending_score(
+        &self,
+        meme_id: &MemeTokenId,
+        now: u64,
+        half_life: u64,
+    ) -> u128 {
+        self.trending_scores
+            .get(meme_id)
+            .map(|score| decay_score(score.score, now.saturating_sub(score.updated_at), half_life))
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn add_trending_likes(&mut self, meme_id: &MemeTokenId, likes: u64) {
+        let now = env::block_timestamp();
+        let half_life = self.likes_config.get().unwrap().trending_half_life;
+        let score =
+            self.get_trending_score(meme_id, now, half_life) + likes as u128 * SCORE_PRECISION;
+        self.trending_scores.insert(
+            meme_id,
+            &TrendingScore {
+                score,
+                updated_at: now,
+            },
+        );
+
+        let bucket = now / TRENDING_BUCKET_DURATION;
+        let mut bucket_memes = self.trending_buckets.get(&bucket).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::TrendingBucketInner { bucket }
+                    .try_to_vec()
+                    .unwrap(),
+            )
+        });
+        if bucket_memes.insert(meme_id) {
+            self.trending_buckets.insert(&bucket, &bucket_memes);
+        }
+    }
+
+    /// Removes up to `MAX_PRUNED_ENTRIES` entries of buckets which cannot be reached
+    /// by the longest trending window, the rest is left for following calls
+    pub(crate) fn prune_trending_buckets(&mut self) {
+        let current_bucket = env::block_timestamp() / TRENDING_BUCKET_DURATION;
+        let first_needed_bucket =
+            (current_bucket + 1).saturating_sub(TrendingWindow::Week.buckets());
+        let mut budget = MAX_PRUNED_ENTRIES;
+
+        while self.first_trending_bucket < first_needed_bucket && budget > 0 {
+            let bucket = self.first_trending_bucket;
+            match self.trending_buckets.get(&bucket) {
+                Some(mut bucket_memes) => {
+                    let meme_ids: Vec<MemeTokenId> =
+                        bucket_memes.iter().take(budget as usize).collect();
+                    budget -= meme_ids.len() as u64;
+                    for meme_id in &meme_ids {
+                        bucket_memes.remove(meme_id);
+                    }
+                    if !bucket_memes.is_empty() {
+                        self.trending_buckets.insert(&bucket, &bucket_memes);
+                        return;
+                    }
+                    self.trending_buckets.remove(&bucket);
+                }
+                None => budget -= 1,
+            }
+            self.first_trending_bucket += 1;
+        }
+    }
+
+    pub(crate) fn remove_trending_score(&mut self, meme_id: &MemeTokenId) {
+        self.trending_scores.remove(meme_id);
+    }
+
+    /// Returns memes liked inside of window sorted by current trending score. Only
+    /// `MAX_TRENDING_SCANNED_ENTRIES` entries of the newest buckets are ranked
+    pub(crate) fn get_trending_meme_ids(&self, window: &TrendingWindow) -> Vec<MemeTokenId> {
+        let now = env::block_timestamp();
+        let current_bucket = now / TRENDING_BUCKET_DURATION;
+        let first_bucket = (current_bucket + 1).saturating_sub(window.buckets());
+        let half_life = self.likes_config.get().unwrap().trending_half_life;
+
+        let mut seen: HashSet<MemeTokenId> = HashSet::new();
+        let mut memes: Vec<(u128, MemeTokenId)> = vec![];
+        let mut scanned = 0;
+        for bucket in (first_bucket..=current_bucket).rev() {
+            if scanned == MAX_TRENDING_SCANNED_ENTRIES {
+                break;
+            }
+            if let Some(bucket_memes) = self.trending_buckets.get(&bucket) {
+                for meme_id in bucket_memes
+                    .iter()
+                    .take(MAX_TRENDING_SCANNED_ENTRIES - scanned)
+                {
+                    scanned += 1;
+                    if !self.is_meme_hidden(&meme_id) && seen.insert(meme_id.clone()) {
+                        memes.push((self.get_trending_score(&meme_id, now, half_life), meme_id));
+                    }
+                }
+            }
+        }
+        memes.sort_by(|(score_a, id_a), (score_b, id_b)| {
+            score_b.cmp(score_a).then_with(|| id_a.cmp(id_b))
+        });
+
+        memes.into_iter().map(|(_, meme_id)| meme_id).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::decay_score;
+
+    #[test]
+    fn decay_score_test() {
+        assert_eq!(decay_score(1_000, 0, 10), 1_000);
+        assert_eq!(decay_score(1_000, 10, 10), 500);
+        assert_eq!(decay_score(1_000, 5, 10), 750);
+        assert_eq!(decay_score(1_000, 25, 10), 188);
+        assert_eq!(decay_score(1_000, 10 * 200, 10), 0);
+    }
+}
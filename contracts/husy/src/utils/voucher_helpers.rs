@@ -0,0 +1,51 @@
+use std::convert::TryFrom;
+
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use near_sdk::{
+    borsh::BorshSerialize,
+    env,
+    json_types::{Base58PublicKey, Base64VecU8},
+    CryptoHash,
+};
+
+use crate::models::{
+    meme_metadata::MemeTokenMetadata,
+    voucher::{Voucher, ED25519_PUBLIC_KEY_LENGTH},
+};
+
+/// Panics unless key is ed25519 key, which is the only curve vouchers can be signed with
+pub(crate) fn assert_ed25519_key(public_key: &Base58PublicKey) {
+    assert!(
+        public_key.0.len() == ED25519_PUBLIC_KEY_LENGTH + 1 && public_key.0[0] == 0,
+        "Only ed25519 public keys are supported"
+    );
+}
+
+pub(crate) fn hash_metadata(metadata: &MemeTokenMetadata) -> Base64VecU8 {
+    Base64VecU8(env::sha256(&metadata.try_to_vec().unwrap()))
+}
+
+pub(crate) fn voucher_message(voucher: &Voucher) -> Vec<u8> {
+    (env::current_account_id(), voucher).try_to_vec().unwrap()
+}
+
+/// Identifies voucher among redeemed ones
+pub(crate) fn hash_voucher_message(message: &[u8]) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+    hash.copy_from_slice(&env::sha256(message));
+    hash
+}
+
+pub(crate) fn assert_valid_signature(
+    public_key: &Base58PublicKey,
+    message: &[u8],
+    signature: &Base64VecU8,
+) {
+    let public_key =
+        PublicKey::from_bytes(&public_key.0[1..]).expect("Registered public key is invalid");
+    let signature = Signature::try_from(&signature.0[..]).expect("Signature is malformed");
+    assert!(
+        public_key.verify(message, &signature).is_ok(),
+        "Voucher signature is invalid"
+    );
+}